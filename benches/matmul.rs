@@ -0,0 +1,39 @@
+#![feature(test)]
+extern crate test;
+
+use arrs::array::gemm;
+use test::Bencher;
+
+// big enough to leave the caches, small enough for the naive path to finish
+const N: usize = 256;
+
+fn matrices() -> (Vec<f32>, Vec<f32>, Vec<f32>) {
+    let a = (0..N * N).map(|i| (i % 7) as f32).collect();
+    let b = (0..N * N).map(|i| (i % 5) as f32).collect();
+    (a, b, vec![0.0; N * N])
+}
+
+#[bench]
+fn naive_f32(bench: &mut Bencher) {
+    let (a, b, mut c) = matrices();
+    bench.iter(|| gemm::naive(N, N, N, &a, &b, &mut c));
+}
+
+#[bench]
+fn blocked_f32(bench: &mut Bencher) {
+    let (a, b, mut c) = matrices();
+    bench.iter(|| gemm::blocked(N, N, N, &a, &b, &mut c, 1));
+}
+
+#[bench]
+fn packed_f32(bench: &mut Bencher) {
+    let (a, b, mut c) = matrices();
+    bench.iter(|| gemm::packed(N, N, N, &a, &b, &mut c, 1));
+}
+
+#[bench]
+fn packed_f32_threaded(bench: &mut Bencher) {
+    let (a, b, mut c) = matrices();
+    let threads = gemm::default_threads();
+    bench.iter(|| gemm::packed(N, N, N, &a, &b, &mut c, threads));
+}
@@ -1,14 +1,34 @@
+mod combine;
+mod compare;
+mod construct;
 mod error;
+pub mod gemm;
 #[cfg(not(no_std))]
 mod idx;
+mod index;
+mod iter;
+mod join;
+mod mask;
+mod reduce;
+mod reshape;
+mod scan;
+mod sort;
+mod stats;
+mod summation;
+mod transpose;
 // mod shape;
 #[macro_use]
 mod macros;
+mod ops;
 use core::fmt::Debug;
-use core::iter::{repeat, Sum};
+use core::iter::Sum;
 use core::ops::{Add, Div, Mul, Sub};
 pub use error::{ArrResult, Error};
-use std::convert::TryInto;
+pub use iter::Iter;
+use iter::Offsets;
+pub use stats::Interpolation;
+use std::borrow::Cow;
+pub use summation::Summation;
 
 // helper functions for compile time use
 pub const fn max_const(a: usize, b: usize) -> usize {
@@ -27,13 +47,216 @@ pub const fn min_const(a: usize, b: usize) -> usize {
     }
 }
 
+// helper functions for strided layouts, shared by `Array` and `ArrayMut`
+
+/// Strides of a contiguous layout, innermost first
+fn contiguous_strides<const NDIMS: usize>(dims: &[usize; NDIMS]) -> [isize; NDIMS] {
+    let mut strides = [0; NDIMS];
+    let mut stride = 1;
+    for (s, &dim) in strides.iter_mut().zip(dims.iter()) {
+        *s = stride as isize;
+        stride *= dim;
+    }
+    strides
+}
+
+/// Bump `index` to the next position, innermost dim first
+fn bump_index<const NDIMS: usize>(index: &mut [usize; NDIMS], dims: &[usize; NDIMS]) {
+    for axis in 0..NDIMS {
+        index[axis] += 1;
+        if index[axis] < dims[axis] {
+            return;
+        }
+        index[axis] = 0;
+    }
+}
+
+fn check_axis<const NDIMS: usize>(axis: usize) -> ArrResult<()> {
+    if axis >= NDIMS {
+        Err(Error::AxisOutOfBounds { axis, ndims: NDIMS })
+    } else {
+        Ok(())
+    }
+}
+
+/// Copy of `values` with the entry at `axis` removed
+fn remove_axis<X: Copy + Default, const NDIMS: usize>(
+    values: &[X; NDIMS],
+    axis: usize,
+) -> [X; NDIMS - 1] {
+    let mut out = [X::default(); NDIMS - 1];
+    let kept = values
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != axis)
+        .map(|(_, &x)| x);
+    for (o, x) in out.iter_mut().zip(kept) {
+        *o = x;
+    }
+    out
+}
+
+/// Dims, strides and offset after fixing `axis` at `index`
+fn select_layout<const NDIMS: usize>(
+    dims: &[usize; NDIMS],
+    strides: &[isize; NDIMS],
+    offset: usize,
+    axis: usize,
+    index: usize,
+) -> ArrResult<([usize; NDIMS - 1], [isize; NDIMS - 1], usize)> {
+    check_axis::<NDIMS>(axis)?;
+    let len = dims[axis];
+    if index >= len {
+        return Err(Error::DerankIndexOutOfBounds { len, index });
+    }
+
+    Ok((
+        remove_axis(dims, axis),
+        remove_axis(strides, axis),
+        (offset as isize + index as isize * strides[axis]) as usize,
+    ))
+}
+
+/// Narrow `axis` to `start..stop`, taking every `step`th value.
+/// A negative `step` walks the range backwards, starting from `stop - 1`.
+fn slice_layout<const NDIMS: usize>(
+    dims: &mut [usize; NDIMS],
+    strides: &mut [isize; NDIMS],
+    offset: &mut usize,
+    axis: usize,
+    start: usize,
+    stop: usize,
+    step: isize,
+) -> ArrResult<()> {
+    check_axis::<NDIMS>(axis)?;
+    let len = dims[axis];
+    if stop < start {
+        return Err(Error::SliceStopBeforeStart { start, stop });
+    } else if stop == start {
+        return Err(Error::SliceZeroWidth { index: start });
+    } else if stop > len {
+        return Err(Error::SliceStopPastEnd { stop, len });
+    } else if step == 0 {
+        return Err(Error::SliceZeroStep);
+    }
+
+    let first = if step > 0 { start } else { stop - 1 };
+
+    *offset = (*offset as isize + first as isize * strides[axis]) as usize;
+    strides[axis] *= step;
+    dims[axis] = (stop - start - 1) / step.unsigned_abs() + 1;
+
+    Ok(())
+}
+
+/// Strides that stretch a view with `dims` and `strides` over `target` dims,
+/// or `None` if it can't be broadcast to `target` without growing it
+fn broadcast_strides<const NDIMS: usize, const NDIMS2: usize>(
+    target: &[usize; NDIMS],
+    dims: &[usize; NDIMS2],
+    strides: &[isize; NDIMS2],
+) -> Option<[isize; NDIMS]> {
+    if dims[NDIMS.min(NDIMS2)..].iter().any(|&dim| dim != 1) {
+        return None;
+    }
+
+    let mut stretched = [0; NDIMS];
+    for ((s, &len), (&dim, &stride)) in stretched
+        .iter_mut()
+        .zip(target)
+        .zip(dims.iter().zip(strides))
+    {
+        if dim == len {
+            *s = stride;
+        } else if dim != 1 {
+            return None;
+        }
+    }
+    Some(stretched)
+}
+
+/// One step of a broadcast walk over two operands, for one dim.
+/// The stretched operand stays put while the other one moves.
+#[derive(Clone, Copy)]
+enum Instruction {
+    PushLinear { stride_a: isize, stride_b: isize },
+    PushStretchA { stride_b: isize },
+    PushStretchB { stride_a: isize },
+    RecurseLinear { stride_a: isize, stride_b: isize },
+    RecurseStretchA { stride_b: isize },
+    RecurseStretchB { stride_a: isize },
+}
+use Instruction::*;
+
+/// Walk both operands outermost dim first, following their strides,
+/// and emit the combined values innermost first
+fn recurse<A, B, R, F, E>(
+    (a, offset_a): (&[A], isize),
+    (b, offset_b): (&[B], isize),
+    dims: &[usize],
+    instrs: &[Instruction],
+    f: &F,
+    emit: &mut E,
+) where
+    A: Element,
+    B: Element,
+    F: Fn(A, B) -> R,
+    E: FnMut(R),
+{
+    let (instr, instrs) = instrs.split_last().unwrap();
+    let (&len, dims) = dims.split_last().unwrap();
+    let steps = 0..len as isize;
+
+    match *instr {
+        PushLinear { stride_a, stride_b } => {
+            for n in steps {
+                emit(f(
+                    a[(offset_a + n * stride_a) as usize],
+                    b[(offset_b + n * stride_b) as usize],
+                ));
+            }
+        }
+        PushStretchA { stride_b } => {
+            let a_n = a[offset_a as usize];
+            for n in steps {
+                emit(f(a_n, b[(offset_b + n * stride_b) as usize]));
+            }
+        }
+        PushStretchB { stride_a } => {
+            let b_n = b[offset_b as usize];
+            for n in steps {
+                emit(f(a[(offset_a + n * stride_a) as usize], b_n));
+            }
+        }
+        RecurseLinear { stride_a, stride_b } => {
+            for n in steps {
+                let (a2, b2) = (offset_a + n * stride_a, offset_b + n * stride_b);
+                recurse((a, a2), (b, b2), dims, instrs, f, emit);
+            }
+        }
+        RecurseStretchA { stride_b } => {
+            for n in steps {
+                let b2 = offset_b + n * stride_b;
+                recurse((a, offset_a), (b, b2), dims, instrs, f, emit);
+            }
+        }
+        RecurseStretchB { stride_a } => {
+            for n in steps {
+                let a2 = offset_a + n * stride_a;
+                recurse((a, a2), (b, offset_b), dims, instrs, f, emit);
+            }
+        }
+    }
+}
+
 /// A base for owning `Array` data
-pub struct ArrayBase<T: ArrType, const NDIMS: usize> {
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArrayBase<T: Element, const NDIMS: usize> {
     dims: [usize; NDIMS],
     data: Vec<T>,
 }
 
-impl<T: ArrType, const NDIMS: usize> ArrayBase<T, NDIMS> {
+impl<T: Element, const NDIMS: usize> ArrayBase<T, NDIMS> {
     pub fn new(dims: [usize; NDIMS], data: Vec<T>) -> ArrResult<Self> {
         let (volume, len) = (dims.iter().product(), data.len());
         if NDIMS == 0 {
@@ -44,127 +267,232 @@ impl<T: ArrType, const NDIMS: usize> ArrayBase<T, NDIMS> {
             Ok(Self { dims, data })
         }
     }
+
+    /// Borrow as an `Array`
+    pub fn view(&self) -> Array<'_, T, NDIMS> {
+        Array::from_base(self)
+    }
+
+    /// Borrow as an `ArrayMut`
+    pub fn view_mut(&mut self) -> ArrayMut<'_, T, NDIMS> {
+        ArrayMut::from_base(self)
+    }
 }
 
 /// A view into an `ArrayBase` object
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Array<'base, T: ArrType, const NDIMS: usize> {
-    dims: [usize; NDIMS], // innermost first, outermost last
+#[derive(Clone, Copy, Debug)]
+pub struct Array<'base, T: Element, const NDIMS: usize> {
+    dims: [usize; NDIMS],    // innermost first, outermost last
+    strides: [isize; NDIMS], // distance in `data` between neighbours along each dim
+    offset: usize,           // position of the first value in `data`
     data: &'base [T],
 }
 
-impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
     /// Get the number of dimensions
     pub fn ndims(&self) -> usize {
         NDIMS
     }
 
-    /// Combine `Array`s of different sizes using array broadcasting
-    pub fn broadcast_combine<const NDIMS2: usize, F: Fn(T, T) -> T>(
-        &self,
-        other: &Array<T, NDIMS2>,
-        combinator: F,
-    ) -> ArrResult<ArrayBase<T, { max_const(NDIMS, NDIMS2) }>> {
-        #[derive(Clone, Copy)]
-        pub enum Instruction {
-            PushLinear,
-            PushStretchA,
-            PushStretchB,
-            RecurseLinear { stride_a: usize, stride_b: usize },
-            RecurseStretchA { stride_b: usize },
-            RecurseStretchB { stride_a: usize },
+    /// Get the length of each dim, innermost first
+    pub fn dims(&self) -> &[usize; NDIMS] {
+        &self.dims
+    }
+
+    /// Get the distance in the underlying data between neighbours along each dim
+    pub fn strides(&self) -> &[isize; NDIMS] {
+        &self.strides
+    }
+
+    /// Whether the values are laid out densely in order, innermost first
+    pub fn is_contiguous(&self) -> bool {
+        self.dims
+            .iter()
+            .zip(
+                self.strides
+                    .iter()
+                    .zip(contiguous_strides(&self.dims).iter()),
+            )
+            .all(|(&dim, (stride, expected))| dim == 1 || stride == expected)
+    }
+
+    /// Get the values as a slice if the view is contiguous
+    pub fn as_slice(&self) -> Option<&'base [T]> {
+        if self.is_contiguous() {
+            let volume = self.dims.iter().product::<usize>();
+            Some(&self.data[self.offset..self.offset + volume])
+        } else {
+            None
         }
-        use Instruction::*;
-
-        let (dims, instrs) = {
-            let mut dims = [0; max_const(NDIMS, NDIMS2)];
-            let mut instrs = [PushLinear; max_const(NDIMS, NDIMS2)];
-
-            let (mut iter_a, mut iter_b) = (self.dims.iter(), other.dims.iter());
-            let (mut stride_a, mut stride_b) = (1, 1);
-
-            for (dim, instruction) in dims.iter_mut().zip(instrs.iter_mut()) {
-                let (next_a, next_b) = (iter_a.next(), iter_b.next());
-
-                let (d, i) = match (next_a, next_b) {
-                    (Some(&a), Some(&b)) if a == b => (a, RecurseLinear { stride_a, stride_b }),
-                    (Some(&a), Some(1)) | (Some(&a), None) => (a, RecurseStretchB { stride_a }),
-                    (Some(1), Some(&b)) | (None, Some(&b)) => (b, RecurseStretchA { stride_b }),
-                    (None, None) => unreachable!(),
-                    _ => {
-                        return Err(Error::Broadcast {
-                            dims1: self.dims.to_vec(),
-                            dims2: other.dims.to_vec(),
-                        })
-                    }
-                };
-
-                stride_a *= next_a.unwrap_or(&1);
-                stride_b *= next_b.unwrap_or(&1);
-
-                *dim = d;
-                *instruction = i;
-            }
+    }
 
-            instrs[0] = match instrs[0] {
-                RecurseLinear { .. } => PushLinear,
-                RecurseStretchA { .. } => PushStretchA,
-                RecurseStretchB { .. } => PushStretchB,
-                _ => unreachable!(),
-            };
+    /// Iterate over the values, innermost dim first
+    pub fn iter(&self) -> Iter<'base, T, NDIMS> {
+        Iter::new(self)
+    }
 
-            (dims, instrs)
-        };
+    pub(crate) fn offsets(&self) -> Offsets<NDIMS> {
+        Offsets::new(self.dims, self.strides, self.offset)
+    }
 
-        // take some data type that has some equivalent to iter() and chunks_exact()
-        // where iter returns values, and chunks_exact returns same type as current
-        fn recurse<T, F>(a: &[T], b: &[T], instrs: &[Instruction], out: &mut Vec<T>, f: &F)
-        where
-            T: ArrType,
-            F: Fn(T, T) -> T,
-        {
-            let (instr, instrs) = instrs.split_last().unwrap();
+    /// Borrow the values contiguously, copying only if the view is strided
+    fn contiguous(&self) -> Cow<'base, [T]> {
+        match self.as_slice() {
+            Some(slice) => Cow::Borrowed(slice),
+            None => Cow::Owned(self.iter().copied().collect()),
+        }
+    }
 
-            match *instr {
-                PushLinear => {
-                    out.extend(a.iter().zip(b.iter()).map(|(&a_n, &b_n)| f(a_n, b_n)));
-                }
-                PushStretchA => {
-                    out.extend(b.iter().map(|&b_n| f(a[0], b_n)));
-                }
-                PushStretchB => {
-                    out.extend(a.iter().map(|&a_n| f(a_n, b[0])));
+    /// Dims of the broadcast result, and the instructions for walking both operands
+    fn broadcast_plan<U: Element, const NDIMS2: usize>(
+        &self,
+        other: &Array<U, NDIMS2>,
+    ) -> ArrResult<(
+        [usize; max_const(NDIMS, NDIMS2)],
+        [Instruction; max_const(NDIMS, NDIMS2)],
+    )> {
+        let mut dims = [0; max_const(NDIMS, NDIMS2)];
+        let mut instrs = [RecurseStretchA { stride_b: 0 }; max_const(NDIMS, NDIMS2)];
+
+        let (mut iter_a, mut iter_b) = (
+            self.dims.iter().zip(self.strides.iter()),
+            other.dims.iter().zip(other.strides.iter()),
+        );
+
+        for (dim, instruction) in dims.iter_mut().zip(instrs.iter_mut()) {
+            let (d, i) = match (iter_a.next(), iter_b.next()) {
+                (Some((&a, &stride_a)), Some((&b, &stride_b))) if a == b => {
+                    (a, RecurseLinear { stride_a, stride_b })
                 }
-                RecurseLinear { stride_a, stride_b } => {
-                    for (a2, b2) in a.chunks_exact(stride_a).zip(b.chunks_exact(stride_b)) {
-                        recurse(a2, b2, instrs, out, f);
-                    }
+                (Some((&a, &stride_a)), Some((1, _))) | (Some((&a, &stride_a)), None) => {
+                    (a, RecurseStretchB { stride_a })
                 }
-                RecurseStretchA { stride_b } => {
-                    for b2 in b.chunks_exact(stride_b) {
-                        recurse(a, b2, instrs, out, f);
-                    }
+                (Some((1, _)), Some((&b, &stride_b))) | (None, Some((&b, &stride_b))) => {
+                    (b, RecurseStretchA { stride_b })
                 }
-                RecurseStretchB { stride_a } => {
-                    for a2 in a.chunks_exact(stride_a) {
-                        recurse(a2, b, instrs, out, f);
-                    }
+                (None, None) => unreachable!(),
+                _ => {
+                    return Err(Error::Broadcast {
+                        dims1: self.dims.to_vec(),
+                        dims2: other.dims.to_vec(),
+                    })
                 }
-            }
+            };
+
+            *dim = d;
+            *instruction = i;
         }
 
+        instrs[0] = match instrs[0] {
+            RecurseLinear { stride_a, stride_b } => PushLinear { stride_a, stride_b },
+            RecurseStretchA { stride_b } => PushStretchA { stride_b },
+            RecurseStretchB { stride_a } => PushStretchB { stride_a },
+            _ => unreachable!(),
+        };
+
+        Ok((dims, instrs))
+    }
+
+    /// Combine `Array`s of different sizes using array broadcasting.
+    /// The operands and the result can each have their own data type.
+    pub fn broadcast_combine<U, R, const NDIMS2: usize, F>(
+        &self,
+        other: &Array<U, NDIMS2>,
+        combinator: F,
+    ) -> ArrResult<ArrayBase<R, { max_const(NDIMS, NDIMS2) }>>
+    where
+        U: Element,
+        R: Element,
+        F: Fn(T, U) -> R,
+    {
+        let (dims, instrs) = self.broadcast_plan(other)?;
+
         let mut data = Vec::with_capacity(dims.iter().product());
 
-        recurse(self.data, other.data, &instrs, &mut data, &combinator);
+        recurse(
+            (self.data, self.offset as isize),
+            (other.data, other.offset as isize),
+            &dims,
+            &instrs,
+            &combinator,
+            &mut |x| data.push(x),
+        );
 
         Ok(ArrayBase { dims, data })
     }
 
+    /// `broadcast_combine`, writing into `out` instead of allocating.
+    /// The dims of `out` must match the broadcast dims exactly.
+    pub fn broadcast_combine_into<U, R, const NDIMS2: usize, F>(
+        &self,
+        other: &Array<U, NDIMS2>,
+        out: &mut ArrayMut<R, { max_const(NDIMS, NDIMS2) }>,
+        combinator: F,
+    ) -> ArrResult<()>
+    where
+        U: Element,
+        R: Element,
+        F: Fn(T, U) -> R,
+    {
+        let (dims, instrs) = self.broadcast_plan(other)?;
+        if dims != out.dims {
+            return Err(Error::BroadcastInto {
+                dims: dims.to_vec(),
+                out: out.dims.to_vec(),
+            });
+        }
+
+        let mut offsets = out.offsets();
+        let data = &mut *out.data;
+
+        recurse(
+            (self.data, self.offset as isize),
+            (other.data, other.offset as isize),
+            &dims,
+            &instrs,
+            &combinator,
+            &mut |x| data[offsets.next().unwrap()] = x,
+        );
+
+        Ok(())
+    }
+
+    /// Dims that `broadcast_combine` with `other` would produce
+    pub fn broadcast_dims<U: Element, const NDIMS2: usize>(
+        &self,
+        other: &Array<U, NDIMS2>,
+    ) -> ArrResult<[usize; max_const(NDIMS, NDIMS2)]> {
+        self.broadcast_plan(other).map(|(dims, _)| dims)
+    }
+
+    /// Stretch to `dims` without copying. Stretched dims get a stride of 0,
+    /// so every value along them is the same value in `data`.
+    pub fn broadcast_to<const M: usize>(&self, dims: [usize; M]) -> ArrResult<Array<'base, T, M>> {
+        let strides = broadcast_strides(&dims, &self.dims, &self.strides).ok_or_else(|| {
+            Error::Broadcast {
+                dims1: self.dims.to_vec(),
+                dims2: dims.to_vec(),
+            }
+        })?;
+
+        Ok(Array {
+            dims,
+            strides,
+            offset: self.offset,
+            data: self.data,
+        })
+    }
+
     /// Convert the data type
-    pub fn as_type<R: ArrType + From<T>>(&self) -> ArrayBase<R, NDIMS> {
+    pub fn as_type<R: Element + From<T>>(&self) -> ArrayBase<R, NDIMS> {
+        self.map(R::from)
+    }
+
+    /// Generate fresh `ArrayBase` by applying `f` to every value
+    pub fn map<R: Element, F: Fn(T) -> R>(&self, f: F) -> ArrayBase<R, NDIMS> {
         ArrayBase {
             dims: self.dims,
-            data: self.data.iter().map(|x| R::from(*x)).collect(),
+            data: self.iter().map(|&x| f(x)).collect(),
         }
     }
 
@@ -172,6 +500,8 @@ impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
     pub fn from_base(base: &'base ArrayBase<T, NDIMS>) -> Self {
         Self {
             dims: base.dims,
+            strides: contiguous_strides(&base.dims),
+            offset: 0,
             data: &base.data[..],
         }
     }
@@ -180,11 +510,67 @@ impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
     pub fn into_base(&self) -> ArrayBase<T, NDIMS> {
         ArrayBase {
             dims: self.dims,
-            data: self.data.to_vec(),
+            data: self.iter().copied().collect(),
         }
     }
 
-    /// Matrix multiplication for 2x2, 2x1, 1x2, and 1x1 `Array`s
+    /// Fix the outermost dim at `index`
+    pub fn derank(&self, index: usize) -> ArrResult<Array<'base, T, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        self.select(NDIMS - 1, index)
+    }
+
+    /// Fix `axis` at `index`
+    pub fn select(&self, axis: usize, index: usize) -> ArrResult<Array<'base, T, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        let (dims, strides, offset) =
+            select_layout(&self.dims, &self.strides, self.offset, axis, index)?;
+
+        Ok(Array {
+            dims,
+            strides,
+            offset,
+            data: self.data,
+        })
+    }
+
+    /// Narrow the outermost dim to `start..stop`
+    pub fn slice(&self, start: usize, stop: usize) -> ArrResult<Self> {
+        self.slice_axis(NDIMS - 1, start, stop, 1)
+    }
+
+    /// Narrow `axis` to `start..stop`, taking every `step`th value.
+    /// A negative `step` walks the range backwards, starting from `stop - 1`.
+    pub fn slice_axis(
+        &self,
+        axis: usize,
+        start: usize,
+        stop: usize,
+        step: isize,
+    ) -> ArrResult<Self> {
+        let mut sliced = *self;
+        slice_layout(
+            &mut sliced.dims,
+            &mut sliced.strides,
+            &mut sliced.offset,
+            axis,
+            start,
+            stop,
+            step,
+        )?;
+
+        Ok(sliced)
+    }
+}
+
+impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Matrix multiplication for 2x2, 2x1, 1x2, and 1x1 `Array`s.
+    /// A vector on the left acts as a row, and a vector on the right acts as a column.
+    /// Every dot product is added up with `ArrType::SUMMATION`, just like `dot`.
     pub fn matmul<const NDIMS2: usize>(
         &self,
         other: &Array<T, NDIMS2>,
@@ -195,122 +581,406 @@ impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
         [(); 2 - NDIMS2]: ,
         [(); NDIMS2 - 1]: ,
     {
-        // can probably figure out which one at compile time
-        match (NDIMS, NDIMS2) {
-            (2, 2) => {
-                // matrix matrix
-                todo!()
-            }
-            (2, 1) => {
-                // matrix vector
-                let (rows_a, cols_a) = (self.dims[0], self.dims[1]);
-                let len_b = other.dims[0];
-                if rows_a != len_b {
-                    return Err(Error::MatMul {
-                        rows_a,
-                        cols_b: len_b,
-                    });
-                }
+        self.matmul_with_threads(other, gemm::default_threads())
+    }
 
-                Ok(ArrayBase {
-                    dims: [cols_a; min_const(NDIMS, NDIMS2)],
-                    data: self
-                        .data
-                        .chunks_exact(cols_a)
-                        .zip(repeat(other.data))
-                        .map(|(a_row, b_col)| {
-                            a_row
-                                .iter()
-                                .zip(b_col.iter())
-                                .map(|(&a_val, &b_val)| a_val * b_val)
-                                .sum()
-                        })
-                        .collect(),
-                })
-            }
-            (1, 2) => {
-                // vector matrix
-                todo!()
-            }
-            (1, 1) => {
-                // vector vector (dot product)
-                let len_a = self.dims[0];
-                let len_b = other.dims[0];
-                if len_a != len_b {
-                    return Err(Error::MatMul {
-                        rows_a: len_a,
-                        cols_b: len_b,
-                    });
-                }
+    /// `matmul`, splitting the work across at most `threads` threads
+    pub fn matmul_with_threads<const NDIMS2: usize>(
+        &self,
+        other: &Array<T, NDIMS2>,
+        threads: usize,
+    ) -> ArrResult<ArrayBase<T, { min_const(NDIMS, NDIMS2) }>>
+    where
+        [(); 2 - NDIMS]: , // at most 2
+        [(); NDIMS - 1]: , // at least 1
+        [(); 2 - NDIMS2]: ,
+        [(); NDIMS2 - 1]: ,
+    {
+        self.matmul_by(other, |m, k, n, a, b, c| {
+            gemm::with_summation(m, k, n, a, b, c, threads, T::SUMMATION)
+        })
+    }
 
-                Ok(ArrayBase {
-                    dims: [1; min_const(NDIMS, NDIMS2)], // always 1 length
-                    data: vec![self
-                        .data
-                        .iter()
-                        .zip(other.data.iter())
-                        .map(|(&a, &b)| a * b)
-                        .sum()],
-                })
-            }
+    /// `matmul`, adding up every dot product with `summation`. The fastest
+    /// kernels sum naively, so `Summation::Naive` uses them as is, while the
+    /// other strategies give up their speed for accuracy.
+    pub fn matmul_with_summation<const NDIMS2: usize>(
+        &self,
+        other: &Array<T, NDIMS2>,
+        summation: Summation,
+    ) -> ArrResult<ArrayBase<T, { min_const(NDIMS, NDIMS2) }>>
+    where
+        [(); 2 - NDIMS]: , // at most 2
+        [(); NDIMS - 1]: , // at least 1
+        [(); 2 - NDIMS2]: ,
+        [(); NDIMS2 - 1]: ,
+    {
+        let threads = gemm::default_threads();
+        self.matmul_by(other, |m, k, n, a, b, c| {
+            gemm::with_summation(m, k, n, a, b, c, threads, summation)
+        })
+    }
+
+    /// `matmul`, with `kernel` filling in the values of the result, see the `gemm` module
+    fn matmul_by<const NDIMS2: usize, F>(
+        &self,
+        other: &Array<T, NDIMS2>,
+        kernel: F,
+    ) -> ArrResult<ArrayBase<T, { min_const(NDIMS, NDIMS2) }>>
+    where
+        [(); 2 - NDIMS]: , // at most 2
+        [(); NDIMS - 1]: , // at least 1
+        [(); 2 - NDIMS2]: ,
+        [(); NDIMS2 - 1]: ,
+        F: FnOnce(usize, usize, usize, &[T], &[T], &mut [T]),
+    {
+        let (cols_a, rows_b) = (self.dims[0], other.dims[NDIMS2 - 1]);
+        if cols_a != rows_b {
+            return Err(Error::MatMul { cols_a, rows_b });
+        }
+
+        // vectors are 1 row matrices on the left and 1 col matrices on the right
+        let rows_a = if NDIMS == 2 { self.dims[1] } else { 1 };
+        let cols_b = if NDIMS2 == 2 { other.dims[0] } else { 1 };
+
+        let mut dims = [0; min_const(NDIMS, NDIMS2)];
+        match (NDIMS, NDIMS2) {
+            (2, 2) => dims.copy_from_slice(&[cols_b, rows_a]),
+            (2, 1) => dims[0] = rows_a,
+            (1, 2) => dims[0] = cols_b,
+            (1, 1) => dims[0] = 1, // always 1 length
             _ => unreachable!(),
         }
+
+        let (data_a, data_b) = (self.contiguous(), other.contiguous());
+        let mut data = vec![T::ZERO; rows_a * cols_b];
+        kernel(rows_a, cols_a, cols_b, &data_a, &data_b, &mut data);
+
+        Ok(ArrayBase { dims, data })
     }
 
-    pub fn derank(&self, index: usize) -> ArrResult<Array<T, { NDIMS - 1 }>>
+    /// Matrix multiplication over stacks of matrices. The innermost two dims
+    /// of each operand hold the matrices, and the rest are broadcast together.
+    pub fn batch_matmul<const NDIMS2: usize>(
+        &self,
+        other: &Array<T, NDIMS2>,
+    ) -> ArrResult<ArrayBase<T, { max_const(NDIMS, NDIMS2) }>>
     where
-        [(); NDIMS - 2]: ,
+        [(); NDIMS - 2]: , // at least 2
+        [(); NDIMS2 - 2]: ,
     {
-        let (&len, dims_slice) = self.dims.split_last().unwrap();
-        if index >= len {
-            return Err(Error::DerankIndexOutOfBounds { len, index });
+        let (cols_a, rows_b) = (self.dims[0], other.dims[1]);
+        if cols_a != rows_b {
+            return Err(Error::MatMul { cols_a, rows_b });
+        }
+        let (rows_a, cols_b) = (self.dims[1], other.dims[0]);
+
+        // matrix dims are fixed at 1 so the offsets only walk the batch dims
+        let mut dims = [1; max_const(NDIMS, NDIMS2)];
+        let (mut strides_a, mut strides_b) =
+            ([0; max_const(NDIMS, NDIMS2)], [0; max_const(NDIMS, NDIMS2)]);
+        for axis in 2..dims.len() {
+            let a = self
+                .dims
+                .get(axis)
+                .map_or((1, 0), |&a| (a, self.strides[axis]));
+            let b = other
+                .dims
+                .get(axis)
+                .map_or((1, 0), |&b| (b, other.strides[axis]));
+
+            dims[axis] = match (a, b) {
+                ((a, stride_a), (b, stride_b)) if a == b => {
+                    strides_a[axis] = stride_a;
+                    strides_b[axis] = stride_b;
+                    a
+                }
+                ((a, stride_a), (1, _)) => {
+                    strides_a[axis] = stride_a;
+                    a
+                }
+                ((1, _), (b, stride_b)) => {
+                    strides_b[axis] = stride_b;
+                    b
+                }
+                _ => {
+                    return Err(Error::Broadcast {
+                        dims1: self.dims.to_vec(),
+                        dims2: other.dims.to_vec(),
+                    })
+                }
+            };
         }
 
-        let stride = dims_slice.iter().product::<usize>();
+        let offsets_a = Offsets::new(dims, strides_a, self.offset);
+        let offsets_b = Offsets::new(dims, strides_b, other.offset);
 
-        Ok(Array {
-            dims: dims_slice.try_into().unwrap(),
-            data: &self.data[stride * index..stride * (index + 1)],
-        })
+        let (threads, len) = (gemm::default_threads(), rows_a * cols_b);
+        if len == 0 {
+            // empty matrices leave nothing to multiply, and no chunks to split into
+            dims[0] = cols_b;
+            dims[1] = rows_a;
+            return Ok(ArrayBase { dims, data: vec![] });
+        }
+        let mut data = vec![T::ZERO; len * dims.iter().product::<usize>()];
+        for ((offset_a, offset_b), out) in offsets_a.zip(offsets_b).zip(data.chunks_exact_mut(len))
+        {
+            let mat_a = Array {
+                dims: [cols_a, rows_a],
+                strides: [self.strides[0], self.strides[1]],
+                offset: offset_a,
+                data: self.data,
+            };
+            let mat_b = Array {
+                dims: [cols_b, rows_b],
+                strides: [other.strides[0], other.strides[1]],
+                offset: offset_b,
+                data: other.data,
+            };
+
+            let (data_a, data_b) = (mat_a.contiguous(), mat_b.contiguous());
+            gemm::with_summation(
+                rows_a,
+                cols_a,
+                cols_b,
+                &data_a,
+                &data_b,
+                out,
+                threads,
+                T::SUMMATION,
+            );
+        }
+
+        dims[0] = cols_b;
+        dims[1] = rows_a;
+        Ok(ArrayBase { dims, data })
     }
+}
 
-    pub fn slice(&self, start: usize, stop: usize) -> ArrResult<Self> {
-        let (&len, dims_slice) = self.dims.split_last().unwrap();
-        if stop < start {
-            return Err(Error::SliceStopBeforeStart { start, stop });
-        } else if stop == start {
-            return Err(Error::SliceZeroWidth { index: start });
-        } else if stop > len {
-            return Err(Error::SliceStopPastEnd { stop, len });
+impl<'base, 'other, T: Element, const NDIMS: usize> PartialEq<Array<'other, T, NDIMS>>
+    for Array<'base, T, NDIMS>
+{
+    fn eq(&self, other: &Array<'other, T, NDIMS>) -> bool {
+        self.dims == other.dims && self.iter().eq(other.iter())
+    }
+}
+
+impl<'base, T: Element + Eq, const NDIMS: usize> Eq for Array<'base, T, NDIMS> {}
+
+/// A mutable view into an `ArrayBase` object
+#[derive(Debug)]
+pub struct ArrayMut<'base, T: Element, const NDIMS: usize> {
+    dims: [usize; NDIMS],    // innermost first, outermost last
+    strides: [isize; NDIMS], // distance in `data` between neighbours along each dim
+    offset: usize,           // position of the first value in `data`
+    data: &'base mut [T],
+}
+
+impl<'base, T: Element, const NDIMS: usize> ArrayMut<'base, T, NDIMS> {
+    /// Get the number of dimensions
+    pub fn ndims(&self) -> usize {
+        NDIMS
+    }
+
+    /// Get the length of each dim, innermost first
+    pub fn dims(&self) -> &[usize; NDIMS] {
+        &self.dims
+    }
+
+    /// Generate fresh `ArrayMut` from an `ArrayBase`
+    pub fn from_base(base: &'base mut ArrayBase<T, NDIMS>) -> Self {
+        Self {
+            dims: base.dims,
+            strides: contiguous_strides(&base.dims),
+            offset: 0,
+            data: &mut base.data[..],
+        }
+    }
+
+    /// Borrow as a read-only `Array`
+    pub fn view(&self) -> Array<'_, T, NDIMS> {
+        Array {
+            dims: self.dims,
+            strides: self.strides,
+            offset: self.offset,
+            data: self.data,
         }
+    }
 
-        let stride = dims_slice.iter().product::<usize>();
+    pub(crate) fn offsets(&self) -> Offsets<NDIMS> {
+        Offsets::new(self.dims, self.strides, self.offset)
+    }
 
-        let mut dims = self.dims.clone();
-        *dims.last_mut().unwrap() = stop - start;
+    /// Generate fresh `ArrayBase` from this `ArrayMut`
+    pub fn into_base(&self) -> ArrayBase<T, NDIMS> {
+        self.view().into_base()
+    }
 
-        Ok(Array {
+    /// Set every value to `value`
+    pub fn fill(&mut self, value: T) {
+        for offset in self.offsets() {
+            self.data[offset] = value;
+        }
+    }
+
+    /// Overwrite the values with those of an `Array` of the same dims
+    pub fn assign(&mut self, src: &Array<T, NDIMS>) -> ArrResult<()> {
+        if self.dims != src.dims {
+            return Err(Error::Broadcast {
+                dims1: self.dims.to_vec(),
+                dims2: src.dims.to_vec(),
+            });
+        }
+
+        for (offset, &value) in self.offsets().zip(src.iter()) {
+            self.data[offset] = value;
+        }
+        Ok(())
+    }
+
+    /// Fix the outermost dim at `index`
+    pub fn derank_mut(&mut self, index: usize) -> ArrResult<ArrayMut<'_, T, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        self.select_mut(NDIMS - 1, index)
+    }
+
+    /// Fix `axis` at `index`
+    pub fn select_mut(
+        &mut self,
+        axis: usize,
+        index: usize,
+    ) -> ArrResult<ArrayMut<'_, T, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        let (dims, strides, offset) =
+            select_layout(&self.dims, &self.strides, self.offset, axis, index)?;
+
+        Ok(ArrayMut {
             dims,
-            data: &self.data[stride * start..stride * stop],
+            strides,
+            offset,
+            data: self.data,
         })
     }
+
+    /// Narrow the outermost dim to `start..stop`
+    pub fn slice_mut(&mut self, start: usize, stop: usize) -> ArrResult<ArrayMut<'_, T, NDIMS>> {
+        self.slice_axis_mut(NDIMS - 1, start, stop, 1)
+    }
+
+    /// Narrow `axis` to `start..stop`, taking every `step`th value.
+    /// A negative `step` walks the range backwards, starting from `stop - 1`.
+    pub fn slice_axis_mut(
+        &mut self,
+        axis: usize,
+        start: usize,
+        stop: usize,
+        step: isize,
+    ) -> ArrResult<ArrayMut<'_, T, NDIMS>> {
+        let (mut dims, mut strides, mut offset) = (self.dims, self.strides, self.offset);
+        slice_layout(
+            &mut dims,
+            &mut strides,
+            &mut offset,
+            axis,
+            start,
+            stop,
+            step,
+        )?;
+
+        Ok(ArrayMut {
+            dims,
+            strides,
+            offset,
+            data: self.data,
+        })
+    }
+}
+
+impl<'base, 'other, T: Element, const NDIMS: usize> PartialEq<ArrayMut<'other, T, NDIMS>>
+    for ArrayMut<'base, T, NDIMS>
+{
+    fn eq(&self, other: &ArrayMut<'other, T, NDIMS>) -> bool {
+        self.view() == other.view()
+    }
 }
 
+impl<'base, T: Element + Eq, const NDIMS: usize> Eq for ArrayMut<'base, T, NDIMS> {}
+
+/// Any value that can be held in an array
+pub trait Element: Copy + PartialEq + Debug + Send + Sync {}
+
+impl<T: Copy + PartialEq + Debug + Send + Sync> Element for T {}
+
+/// A value that arrays can do arithmetic with
 pub trait ArrType:
-    Copy
-    + PartialEq
-    + Debug
+    Element
     + Sum
     + Add<Output = Self>
     + Sub<Output = Self>
     + Mul<Output = Self>
     + Div<Output = Self>
+    + Into<f64>
 {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// How `sum`, `mean`, `dot` and `matmul` add values up unless told otherwise
+    const SUMMATION: Summation = Summation::Naive;
+
+    /// Convert back from `f64`, truncating towards zero and saturating for integers
+    fn from_f64(x: f64) -> Self;
+
+    /// Matrix multiply row-major buffers, see the `gemm` module
+    fn gemm(m: usize, k: usize, n: usize, a: &[Self], b: &[Self], c: &mut [Self], threads: usize) {
+        gemm::blocked(m, k, n, a, b, c, threads)
+    }
+}
+
+macro_rules! impl_arrtype {
+    { $inner_type:ty, $zero:expr, $one:expr } => {
+        impl ArrType for $inner_type {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+
+            fn from_f64(x: f64) -> Self {
+                x as $inner_type
+            }
+        }
+    };
+    // floats get pairwise sums to slow the growth of rounding error, and the
+    // vectorized kernels for when `matmul` is asked to sum naively
+    { $inner_type:ty, $zero:expr, $one:expr, float } => {
+        impl ArrType for $inner_type {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+            const SUMMATION: Summation = Summation::Pairwise;
+
+            fn from_f64(x: f64) -> Self {
+                x as $inner_type
+            }
+
+            fn gemm(
+                m: usize,
+                k: usize,
+                n: usize,
+                a: &[Self],
+                b: &[Self],
+                c: &mut [Self],
+                threads: usize,
+            ) {
+                gemm::packed(m, k, n, a, b, c, threads)
+            }
+        }
+    };
 }
 
-impl ArrType for u8 {}
-impl ArrType for i8 {}
-impl ArrType for i16 {}
-impl ArrType for i32 {}
-impl ArrType for f32 {}
-impl ArrType for f64 {}
+impl_arrtype! { u8, 0, 1 }
+impl_arrtype! { i8, 0, 1 }
+impl_arrtype! { i16, 0, 1 }
+impl_arrtype! { i32, 0, 1 }
+impl_arrtype! { f32, 0.0, 1.0, float }
+impl_arrtype! { f64, 0.0, 1.0, float }
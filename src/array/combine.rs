@@ -0,0 +1,138 @@
+use crate::array::{max_const, ArrResult, ArrType, Array, ArrayBase, Element, Error};
+use std::convert::TryInto;
+
+/// Broadcast dims of any number of operands, innermost first
+fn broadcast_dims_many(operands: &[&[usize]]) -> ArrResult<Vec<usize>> {
+    let ndims = operands.iter().map(|dims| dims.len()).max().unwrap_or(0);
+
+    (0..ndims)
+        .map(|axis| {
+            let lens = operands
+                .iter()
+                .map(|dims| dims.get(axis).copied().unwrap_or(1));
+            // every len has to be 1 or the same other len, which may be 0
+            let len = lens.clone().find(|&l| l != 1).unwrap_or(1);
+            if lens.into_iter().all(|l| l == 1 || l == len) {
+                Ok(len)
+            } else {
+                Err(Error::BroadcastMany {
+                    dims: operands.iter().map(|dims| dims.to_vec()).collect(),
+                })
+            }
+        })
+        .collect()
+}
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Combine three `Array`s of different sizes using array broadcasting.
+    /// The operands and the result can each have their own data type.
+    pub fn broadcast_combine3<U, V, R, const NDIMS2: usize, const NDIMS3: usize, F>(
+        &self,
+        b: &Array<U, NDIMS2>,
+        c: &Array<V, NDIMS3>,
+        combinator: F,
+    ) -> ArrResult<ArrayBase<R, { max_const(max_const(NDIMS, NDIMS2), NDIMS3) }>>
+    where
+        U: Element,
+        V: Element,
+        R: Element,
+        F: Fn(T, U, V) -> R,
+    {
+        // as many dims as the operand with the most, so the conversion can't fail
+        let dims: [usize; max_const(max_const(NDIMS, NDIMS2), NDIMS3)] =
+            broadcast_dims_many(&[&self.dims, &b.dims, &c.dims])?[..]
+                .try_into()
+                .unwrap();
+
+        let (a, b, c) = (
+            self.broadcast_to(dims)?,
+            b.broadcast_to(dims)?,
+            c.broadcast_to(dims)?,
+        );
+        let data = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((&a_n, &b_n), &c_n)| combinator(a_n, b_n, c_n))
+            .collect();
+
+        Ok(ArrayBase { dims, data })
+    }
+
+    /// Combine any number of `Array`s of the same number of dims using array broadcasting.
+    /// `combinator` gets one value from each array, in order.
+    pub fn broadcast_combine_many<F: Fn(&[T]) -> T>(
+        arrays: &[Array<T, NDIMS>],
+        combinator: F,
+    ) -> ArrResult<ArrayBase<T, NDIMS>> {
+        if arrays.is_empty() {
+            return Err(Error::CombineEmpty);
+        }
+
+        let operands = arrays
+            .iter()
+            .map(|array| &array.dims[..])
+            .collect::<Vec<_>>();
+        // every operand has `NDIMS` dims, so the conversion can't fail
+        let dims: [usize; NDIMS] = broadcast_dims_many(&operands)?[..].try_into().unwrap();
+
+        let mut iters = arrays
+            .iter()
+            .map(|array| array.broadcast_to(dims).map(|stretched| stretched.iter()))
+            .collect::<ArrResult<Vec<_>>>()?;
+
+        let volume = dims.iter().product();
+        let mut values = Vec::with_capacity(arrays.len());
+        let mut data = Vec::with_capacity(volume);
+        for _ in 0..volume {
+            values.clear();
+            values.extend(iters.iter_mut().map(|iter| *iter.next().unwrap()));
+            data.push(combinator(&values));
+        }
+
+        Ok(ArrayBase { dims, data })
+    }
+}
+
+impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Pick from `x` where this is nonzero, and from `y` elsewhere, using array broadcasting
+    pub fn where_<U: Element, const NDIMS2: usize, const NDIMS3: usize>(
+        &self,
+        x: &Array<U, NDIMS2>,
+        y: &Array<U, NDIMS3>,
+    ) -> ArrResult<ArrayBase<U, { max_const(max_const(NDIMS, NDIMS2), NDIMS3) }>> {
+        self.broadcast_combine3(
+            x,
+            y,
+            |cond, x_n, y_n| if cond != T::ZERO { x_n } else { y_n },
+        )
+    }
+
+    /// Compute `self * b + c` in one pass, using array broadcasting
+    pub fn fma<const NDIMS2: usize, const NDIMS3: usize>(
+        &self,
+        b: &Array<T, NDIMS2>,
+        c: &Array<T, NDIMS3>,
+    ) -> ArrResult<ArrayBase<T, { max_const(max_const(NDIMS, NDIMS2), NDIMS3) }>> {
+        self.broadcast_combine3(b, c, |a_n, b_n, c_n| a_n * b_n + c_n)
+    }
+}
+
+impl<'base, T: Element + PartialOrd, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Limit the values to between `lo` and `hi`, using array broadcasting
+    pub fn clip<const NDIMS2: usize, const NDIMS3: usize>(
+        &self,
+        lo: &Array<T, NDIMS2>,
+        hi: &Array<T, NDIMS3>,
+    ) -> ArrResult<ArrayBase<T, { max_const(max_const(NDIMS, NDIMS2), NDIMS3) }>> {
+        self.broadcast_combine3(lo, hi, |x, lo_n, hi_n| {
+            if x < lo_n {
+                lo_n
+            } else if x > hi_n {
+                hi_n
+            } else {
+                x
+            }
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::array::{max_const, ArrResult, Array, ArrayBase, Element};
+
+// Elementwise comparisons broadcast like `broadcast_combine`. `eq` and `ne` are
+// taken by `PartialEq`, which compares whole arrays, hence `eq_elem` and `ne_elem`.
+
+macro_rules! impl_compare {
+    { $bound:ident, $(($method:ident, $symbol:tt)),* } => {
+        impl<'base, T: Element + $bound, const NDIMS: usize> Array<'base, T, NDIMS> {
+            $(
+                /// Elementwise comparison using array broadcasting
+                pub fn $method<const NDIMS2: usize>(
+                    &self,
+                    other: &Array<T, NDIMS2>,
+                ) -> ArrResult<ArrayBase<bool, { max_const(NDIMS, NDIMS2) }>> {
+                    self.broadcast_combine(other, |a, b| a $symbol b)
+                }
+            )*
+        }
+    };
+}
+
+impl_compare! { PartialEq, (eq_elem, ==), (ne_elem, !=) }
+impl_compare! { PartialOrd, (lt, <), (le, <=), (gt, >), (ge, >=) }
+
+impl<'base, const NDIMS: usize> Array<'base, bool, NDIMS> {
+    /// Whether any value is `true`
+    pub fn any(&self) -> bool {
+        self.iter().any(|&x| x)
+    }
+
+    /// Whether every value is `true`
+    pub fn all(&self) -> bool {
+        self.iter().all(|&x| x)
+    }
+
+    /// Pick from `x` where this is `true`, and from `y` elsewhere, using array broadcasting
+    pub fn where_<U: Element, const NDIMS2: usize, const NDIMS3: usize>(
+        &self,
+        x: &Array<U, NDIMS2>,
+        y: &Array<U, NDIMS3>,
+    ) -> ArrResult<ArrayBase<U, { max_const(max_const(NDIMS, NDIMS2), NDIMS3) }>> {
+        self.broadcast_combine3(x, y, |cond, x_n, y_n| if cond { x_n } else { y_n })
+    }
+}
+
+impl<const NDIMS: usize> ArrayBase<bool, NDIMS> {
+    /// Whether any value is `true`
+    pub fn any(&self) -> bool {
+        self.data.iter().any(|&x| x)
+    }
+
+    /// Whether every value is `true`
+    pub fn all(&self) -> bool {
+        self.data.iter().all(|&x| x)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::array::{bump_index, ArrResult, ArrType, ArrayBase, Element, Error};
+
+/// Check that `dims` describes a non-empty shape
+fn check_dims<const NDIMS: usize>(dims: &[usize; NDIMS]) -> ArrResult<()> {
+    if NDIMS == 0 {
+        Err(Error::ShapeZeroDims)
+    } else if dims.contains(&0) {
+        Err(Error::ShapeZeroLenDim {
+            dims: dims.to_vec(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+impl<T: Element, const NDIMS: usize> ArrayBase<T, NDIMS> {
+    /// Generate an `ArrayBase` filled with `value`
+    pub fn full(dims: [usize; NDIMS], value: T) -> ArrResult<Self> {
+        check_dims(&dims)?;
+
+        Ok(Self {
+            dims,
+            data: vec![value; dims.iter().product()],
+        })
+    }
+
+    /// Generate an `ArrayBase` by calling `f` with the index of each value
+    pub fn from_fn<F: FnMut([usize; NDIMS]) -> T>(
+        dims: [usize; NDIMS],
+        mut f: F,
+    ) -> ArrResult<Self> {
+        check_dims(&dims)?;
+
+        let volume = dims.iter().product();
+        let mut data = Vec::with_capacity(volume);
+        let mut index = [0; NDIMS];
+        for _ in 0..volume {
+            data.push(f(index));
+            bump_index(&mut index, &dims);
+        }
+
+        Ok(Self { dims, data })
+    }
+}
+
+impl<T: ArrType, const NDIMS: usize> ArrayBase<T, NDIMS> {
+    /// Generate an `ArrayBase` filled with 0
+    pub fn zeros(dims: [usize; NDIMS]) -> ArrResult<Self> {
+        Self::full(dims, T::ZERO)
+    }
+
+    /// Generate an `ArrayBase` filled with 1
+    pub fn ones(dims: [usize; NDIMS]) -> ArrResult<Self> {
+        Self::full(dims, T::ONE)
+    }
+}
+
+impl<T: ArrType> ArrayBase<T, 1> {
+    /// Generate values from `start` up to but not including `stop`, `step` apart.
+    /// The count is worked out up front and each value is `start + step * i`,
+    /// so float rounding can't add a value past `stop`.
+    pub fn arange(start: T, stop: T, step: T) -> ArrResult<Self> {
+        if step == T::ZERO {
+            return Err(Error::ArangeZeroStep);
+        }
+
+        // `f64` holds every integer type exactly, so nothing overflows on the way
+        let (start, stop, step): (f64, f64, f64) = (start.into(), stop.into(), step.into());
+        let len = ((stop - start) / step).ceil().max(0.0) as usize;
+        check_dims(&[len])?;
+
+        Ok(Self {
+            dims: [len],
+            data: (0..len)
+                .map(|i| T::from_f64(start + step * i as f64))
+                .collect(),
+        })
+    }
+
+    /// Generate `num` evenly spaced values from `start` to `stop` inclusive.
+    /// The values are worked out in `f64`, so integers round towards zero.
+    pub fn linspace(start: T, stop: T, num: usize) -> ArrResult<Self> {
+        check_dims(&[num])?;
+
+        let data = if num == 1 {
+            vec![start]
+        } else {
+            let (first, last): (f64, f64) = (start.into(), stop.into());
+            let step = (last - first) / (num - 1) as f64;
+            (0..num - 1)
+                .map(|i| T::from_f64(first + step * i as f64))
+                .chain(Some(stop))
+                .collect()
+        };
+
+        Ok(Self { dims: [num], data })
+    }
+}
+
+impl<T: ArrType> ArrayBase<T, 2> {
+    /// Generate a matrix with 1 on the diagonal and 0 elsewhere
+    pub fn eye(dims: [usize; 2]) -> ArrResult<Self> {
+        Self::from_fn(dims, |[col, row]| if col == row { T::ONE } else { T::ZERO })
+    }
+
+    /// Generate an `n` x `n` identity matrix
+    pub fn identity(n: usize) -> ArrResult<Self> {
+        Self::eye([n, n])
+    }
+}
@@ -6,13 +6,21 @@ pub type ArrResult<T> = Result<T, Error>;
 #[derive(Debug, PartialEq)]
 pub enum Error {
     MatMul {
-        rows_a: usize,
-        cols_b: usize,
+        cols_a: usize,
+        rows_b: usize,
     },
     Broadcast {
         dims1: Vec<usize>,
         dims2: Vec<usize>,
     },
+    BroadcastMany {
+        dims: Vec<Vec<usize>>,
+    },
+    CombineEmpty,
+    BroadcastInto {
+        dims: Vec<usize>,
+        out: Vec<usize>,
+    },
     ShapeZeroDims,
     ShapeZeroLenDim {
         dims: Vec<usize>,
@@ -43,6 +51,54 @@ pub enum Error {
         stop: usize,
         len: usize,
     },
+    SliceZeroStep,
+    AxisOutOfBounds {
+        axis: usize,
+        ndims: usize,
+    },
+    PermuteAxes {
+        axes: Vec<usize>,
+    },
+    NotContiguous {
+        dims: Vec<usize>,
+        strides: Vec<isize>,
+    },
+    SqueezeLenNotOne {
+        axis: usize,
+        len: usize,
+    },
+    JoinEmpty,
+    JoinMismatchedDims {
+        dims1: Vec<usize>,
+        dims2: Vec<usize>,
+        axis: usize,
+    },
+    Split {
+        len: usize,
+        sections: usize,
+    },
+    ArangeZeroStep,
+    IndexOutOfBounds {
+        axis: usize,
+        len: usize,
+        index: usize,
+    },
+    ReduceEmpty,
+    VarDdof {
+        len: usize,
+        ddof: usize,
+    },
+    Percentile {
+        q: f64,
+    },
+    Histogram {
+        bins: usize,
+        range: (f64, f64),
+    },
+    Gradient {
+        axis: usize,
+        len: usize,
+    },
     IdxIO {
         // io::Error doesn't impl PartialEq, which is annoying
         message: String,
@@ -63,11 +119,11 @@ impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Error::*;
         match self {
-            MatMul { rows_a, cols_b } => {
+            MatMul { cols_a, rows_b } => {
                 write!(
                     f,
-                    "cannot matrix multiply: first has {} rows, second has {} cols",
-                    rows_a, cols_b
+                    "cannot matrix multiply: first has {} cols, second has {} rows",
+                    cols_a, rows_b
                 )
             }
             Broadcast { dims1, dims2 } => {
@@ -77,6 +133,23 @@ impl Display for Error {
                     dims1, dims2
                 )
             }
+            BroadcastMany { dims } => {
+                write!(f, "operands could not be broadcast together with shapes")?;
+                for (i, d) in dims.iter().enumerate() {
+                    write!(f, "{} {:?}", if i == 0 { "" } else { "," }, d)?;
+                }
+                Ok(())
+            }
+            CombineEmpty => {
+                write!(f, "need at least one array to combine")
+            }
+            BroadcastInto { dims, out } => {
+                write!(
+                    f,
+                    "broadcast result with shape {:?} cannot be written into output with shape {:?}",
+                    dims, out
+                )
+            }
             ShapeZeroDims => {
                 write!(f, "shape cannot be constructed with 0 dims")
             }
@@ -132,6 +205,83 @@ impl Display for Error {
                     dim_width, slice_width
                 )
             }
+            SliceZeroStep => {
+                write!(f, "slice step cannot be 0")
+            }
+            AxisOutOfBounds { axis, ndims } => {
+                write!(
+                    f,
+                    "axis {} is out of bounds for array of {} dims",
+                    axis, ndims
+                )
+            }
+            PermuteAxes { axes } => {
+                write!(f, "axes {:?} are not a permutation of the dims", axes)
+            }
+            NotContiguous { dims, strides } => {
+                write!(
+                    f,
+                    "view with dims {:?} and strides {:?} is not contiguous",
+                    dims, strides
+                )
+            }
+            SqueezeLenNotOne { axis, len } => {
+                write!(
+                    f,
+                    "cannot squeeze axis {} with len {}, must be 1",
+                    axis, len
+                )
+            }
+            JoinEmpty => {
+                write!(f, "need at least one array to join")
+            }
+            JoinMismatchedDims { dims1, dims2, axis } => {
+                write!(
+                    f,
+                    "cannot join arrays with shapes {:?}, {:?} along axis {}",
+                    dims1, dims2, axis
+                )
+            }
+            Split { len, sections } => {
+                write!(f, "cannot split len {} into {} sections", len, sections)
+            }
+            ArangeZeroStep => {
+                write!(f, "arange step cannot be 0")
+            }
+            IndexOutOfBounds { axis, len, index } => {
+                write!(
+                    f,
+                    "index {} is out of bounds for axis {} with len {}",
+                    index, axis, len
+                )
+            }
+            ReduceEmpty => {
+                write!(f, "cannot reduce an empty array without an identity value")
+            }
+            VarDdof { len, ddof } => {
+                write!(
+                    f,
+                    "cannot take the variance of {} values with ddof {}",
+                    len, ddof
+                )
+            }
+            Percentile { q } => {
+                write!(f, "percentile {} is outside 0..=100", q)
+            }
+            Histogram { bins, range } => {
+                write!(
+                    f,
+                    "cannot make {} bins over the range {}..{}",
+                    bins, range.0, range.1
+                )
+            }
+            Gradient { axis, len } => {
+                write!(
+                    f,
+                    "gradient needs at least 2 values along axis {}, found {}",
+                    axis, len
+                )
+            }
             IdxIO { message } => f.write_str(&message),
             IdxReadUnaccepted => {
                 write!(f, "reader no longer providing bytes")
@@ -0,0 +1,238 @@
+//! General matrix multiplication kernels behind `Array::matmul`.
+//!
+//! Every kernel computes `c = a * b` for row-major buffers, where `a` is
+//! `m` rows of `k` values, `b` is `k` rows of `n` values, and `c` is `m` rows
+//! of `n` values. The rows of `c` are split across `threads` scoped threads.
+
+use crate::array::{ArrType, Summation};
+use std::thread;
+
+// register tile computed by one call to `kernel`
+const MR: usize = 4;
+const NR: usize = 8;
+
+// cache blocks: an `MC` x `KC` block of `a` is packed at a time,
+// and `b` is packed in `KC` deep slabs
+const MC: usize = 64;
+const KC: usize = 256;
+
+/// Number of threads used by `Array::matmul`
+pub fn default_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// One dot product per value of `c`, walking down the columns of `b`.
+/// This is the simplest kernel and the reference the others are checked against.
+pub fn naive<T: ArrType>(m: usize, k: usize, n: usize, a: &[T], b: &[T], c: &mut [T]) {
+    if fill_empty(m, k, n, c) {
+        return;
+    }
+
+    for (a_row, c_row) in a.chunks_exact(k).zip(c.chunks_exact_mut(n)).take(m) {
+        for (col, c_val) in c_row.iter_mut().enumerate() {
+            *c_val = a_row
+                .iter()
+                .zip(b[col..].iter().step_by(n))
+                .map(|(&a_val, &b_val)| a_val * b_val)
+                .sum();
+        }
+    }
+}
+
+/// Accumulate whole rows of `b` into each row of `c`, so that every buffer
+/// is read in order. Works for any `ArrType`.
+pub fn blocked<T: ArrType>(
+    m: usize,
+    k: usize,
+    n: usize,
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    threads: usize,
+) {
+    split_rows(m, k, n, a, c, threads, |a_rows, c_rows| {
+        for (a_row, c_row) in a_rows.chunks_exact(k).zip(c_rows.chunks_exact_mut(n)) {
+            c_row.fill(T::ZERO);
+            for (&a_val, b_row) in a_row.iter().zip(b.chunks_exact(n)) {
+                for (c_val, &b_val) in c_row.iter_mut().zip(b_row) {
+                    *c_val = *c_val + a_val * b_val;
+                }
+            }
+        }
+    });
+}
+
+/// Pack `a` and `b` into cache sized panels and multiply them
+/// `MR` x `NR` tiles at a time, keeping each tile in registers.
+/// Used for `f32` and `f64`, where the tile loops vectorize.
+pub fn packed<T: ArrType>(
+    m: usize,
+    k: usize,
+    n: usize,
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    threads: usize,
+) {
+    // every thread reads all of `b`, so it's packed once up front
+    let slabs = (0..k)
+        .step_by(KC)
+        .map(|p0| pack_b(&b[p0 * n..], KC.min(k - p0), n))
+        .collect::<Vec<_>>();
+
+    split_rows(m, k, n, a, c, threads, |a_rows, c_rows| {
+        c_rows.fill(T::ZERO);
+        let rows = c_rows.len() / n;
+        let mut packed_a = Vec::with_capacity(MC * KC);
+
+        for (slab, p0) in slabs.iter().zip((0..k).step_by(KC)) {
+            let kc = KC.min(k - p0);
+
+            for i0 in (0..rows).step_by(MC) {
+                let mc = MC.min(rows - i0);
+                pack_a(&mut packed_a, &a_rows[i0 * k + p0..], mc, kc, k);
+
+                for (b_panel, j0) in slab.chunks_exact(kc * NR).zip((0..n).step_by(NR)) {
+                    for (a_panel, ii) in packed_a.chunks_exact(kc * MR).zip((0..mc).step_by(MR)) {
+                        let tile = kernel(a_panel, b_panel);
+
+                        let c_tile = c_rows[(i0 + ii) * n + j0..].chunks_mut(n);
+                        for (c_row, tile_row) in c_tile.zip(tile.iter()).take(MR.min(mc - ii)) {
+                            for (c_val, &t) in c_row.iter_mut().zip(tile_row).take(NR.min(n - j0)) {
+                                *c_val = *c_val + t;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// One dot product per value of `c` like `naive`, but added up with `summation`.
+/// `b` is transposed first so both operands of every dot product are read in order.
+#[allow(clippy::too_many_arguments)]
+pub fn summed<T: ArrType>(
+    m: usize,
+    k: usize,
+    n: usize,
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    threads: usize,
+    summation: Summation,
+) {
+    if fill_empty(m, k, n, c) {
+        return;
+    }
+
+    let mut b_cols = vec![T::ZERO; k * n];
+    for (p, b_row) in b.chunks_exact(n).take(k).enumerate() {
+        for (j, &b_val) in b_row.iter().enumerate() {
+            b_cols[j * k + p] = b_val;
+        }
+    }
+
+    split_rows(m, k, n, a, c, threads, |a_rows, c_rows| {
+        for (a_row, c_row) in a_rows.chunks_exact(k).zip(c_rows.chunks_exact_mut(n)) {
+            for (c_val, b_col) in c_row.iter_mut().zip(b_cols.chunks_exact(k)) {
+                *c_val = summation.dot(a_row.iter().copied(), b_col.iter().copied());
+            }
+        }
+    });
+}
+
+/// `ArrType::gemm` for `Summation::Naive`, which is how its kernels add up,
+/// and `summed` for the other strategies
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn with_summation<T: ArrType>(
+    m: usize,
+    k: usize,
+    n: usize,
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    threads: usize,
+    summation: Summation,
+) {
+    match summation {
+        Summation::Naive => T::gemm(m, k, n, a, b, c, threads),
+        _ => summed(m, k, n, a, b, c, threads, summation),
+    }
+}
+
+/// Fill in `c` if `a` or `b` has no values, where the kernels would chunk
+/// rows of len 0. An empty sum is 0, so `c` is all zeros. Returns whether it did.
+fn fill_empty<T: ArrType>(m: usize, k: usize, n: usize, c: &mut [T]) -> bool {
+    if k == 0 || n == 0 {
+        c[..m * n].fill(T::ZERO);
+        true
+    } else {
+        false
+    }
+}
+
+/// Run `f` on matching row chunks of `a` and `c`, one chunk per thread
+fn split_rows<T, F>(m: usize, k: usize, n: usize, a: &[T], c: &mut [T], threads: usize, f: F)
+where
+    T: ArrType,
+    F: Fn(&[T], &mut [T]) + Sync,
+{
+    if fill_empty(m, k, n, c) {
+        return;
+    }
+
+    // no point splitting below one `MC` block per thread
+    let threads = threads.min(m.div_ceil(MC)).max(1);
+    if threads == 1 {
+        return f(&a[..m * k], &mut c[..m * n]);
+    }
+
+    let rows_per_thread = m.div_ceil(threads);
+    let f = &f;
+    thread::scope(|scope| {
+        for (a_rows, c_rows) in a[..m * k]
+            .chunks(rows_per_thread * k)
+            .zip(c[..m * n].chunks_mut(rows_per_thread * n))
+        {
+            scope.spawn(move || f(a_rows, c_rows));
+        }
+    });
+}
+
+/// Lay out `kc` rows of `b` as `NR` wide column panels, each stored row by row.
+/// The last panel is padded with zeros.
+fn pack_b<T: ArrType>(b: &[T], kc: usize, n: usize) -> Vec<T> {
+    let panels = n.div_ceil(NR);
+    let mut packed = Vec::with_capacity(panels * kc * NR);
+    for j0 in (0..n).step_by(NR) {
+        for b_row in b.chunks(n).take(kc) {
+            packed.extend((j0..j0 + NR).map(|j| if j < n { b_row[j] } else { T::ZERO }));
+        }
+    }
+    packed
+}
+
+/// Lay out an `mc` x `kc` block of `a` as `MR` tall row panels, each stored
+/// column by column. The last panel is padded with zeros.
+fn pack_a<T: ArrType>(packed: &mut Vec<T>, a: &[T], mc: usize, kc: usize, k: usize) {
+    packed.clear();
+    for i0 in (0..mc).step_by(MR) {
+        for p in 0..kc {
+            packed.extend((i0..i0 + MR).map(|i| if i < mc { a[i * k + p] } else { T::ZERO }));
+        }
+    }
+}
+
+/// Multiply an `MR` tall panel of `a` by an `NR` wide panel of `b`
+fn kernel<T: ArrType>(a_panel: &[T], b_panel: &[T]) -> [[T; NR]; MR] {
+    let mut tile = [[T::ZERO; NR]; MR];
+    for (a_col, b_row) in a_panel.chunks_exact(MR).zip(b_panel.chunks_exact(NR)) {
+        for (tile_row, &a_val) in tile.iter_mut().zip(a_col) {
+            for (t, &b_val) in tile_row.iter_mut().zip(b_row) {
+                *t = *t + a_val * b_val;
+            }
+        }
+    }
+    tile
+}
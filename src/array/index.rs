@@ -0,0 +1,194 @@
+use crate::array::{
+    bump_index, check_axis, contiguous_strides, ArrResult, Array, ArrayBase, ArrayMut, Element,
+    Error,
+};
+use core::ops::{Index, IndexMut};
+
+/// Position in `data` of the value at `index`, innermost first
+fn index_offset<const NDIMS: usize>(
+    dims: &[usize; NDIMS],
+    strides: &[isize; NDIMS],
+    offset: usize,
+    index: &[usize; NDIMS],
+) -> ArrResult<usize> {
+    for (axis, (&len, &i)) in dims.iter().zip(index).enumerate() {
+        if i >= len {
+            return Err(Error::IndexOutOfBounds {
+                axis,
+                len,
+                index: i,
+            });
+        }
+    }
+
+    Ok(unchecked_offset(strides, offset, index))
+}
+
+/// Position in `data` of the value at `index`, which must be in bounds
+fn unchecked_offset<const NDIMS: usize>(
+    strides: &[isize; NDIMS],
+    offset: usize,
+    index: &[usize; NDIMS],
+) -> usize {
+    let offset = offset as isize
+        + strides
+            .iter()
+            .zip(index)
+            .map(|(&stride, &i)| i as isize * stride)
+            .sum::<isize>();
+    offset as usize
+}
+
+/// Collect `indices`, checking each against the `len` of `axis`
+fn checked_indices(indices: &Array<usize, 1>, axis: usize, len: usize) -> ArrResult<Vec<usize>> {
+    indices
+        .iter()
+        .map(|&index| {
+            if index < len {
+                Ok(index)
+            } else {
+                Err(Error::IndexOutOfBounds { axis, len, index })
+            }
+        })
+        .collect()
+}
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Read the value at `index`, innermost first
+    pub fn get(&self, index: [usize; NDIMS]) -> ArrResult<&'base T> {
+        let offset = index_offset(&self.dims, &self.strides, self.offset, &index)?;
+        Ok(&self.data[offset])
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> ArrayMut<'base, T, NDIMS> {
+    /// Read the value at `index`, innermost first
+    pub fn get(&self, index: [usize; NDIMS]) -> ArrResult<&T> {
+        let offset = index_offset(&self.dims, &self.strides, self.offset, &index)?;
+        Ok(&self.data[offset])
+    }
+
+    /// Borrow the value at `index` mutably, innermost first
+    pub fn get_mut(&mut self, index: [usize; NDIMS]) -> ArrResult<&mut T> {
+        let offset = index_offset(&self.dims, &self.strides, self.offset, &index)?;
+        Ok(&mut self.data[offset])
+    }
+}
+
+impl<T: Element, const NDIMS: usize> ArrayBase<T, NDIMS> {
+    /// Read the value at `index`, innermost first
+    pub fn get(&self, index: [usize; NDIMS]) -> ArrResult<&T> {
+        Array::from_base(self).get(index)
+    }
+
+    /// Borrow the value at `index` mutably, innermost first
+    pub fn get_mut(&mut self, index: [usize; NDIMS]) -> ArrResult<&mut T> {
+        let offset = index_offset(&self.dims, &contiguous_strides(&self.dims), 0, &index)?;
+        Ok(&mut self.data[offset])
+    }
+}
+
+// Fancy indexing picks positions along one axis from an index array, so
+// `take` can repeat and reorder them while `slice_axis` can only stride.
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Gather the positions in `indices` along `axis` into a fresh `ArrayBase`
+    pub fn take(&self, indices: &Array<usize, 1>, axis: usize) -> ArrResult<ArrayBase<T, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        let indices = checked_indices(indices, axis, self.dims[axis])?;
+
+        let mut dims = self.dims;
+        dims[axis] = indices.len();
+        let volume = dims.iter().product();
+
+        let mut data = Vec::with_capacity(volume);
+        let mut index = [0; NDIMS];
+        for _ in 0..volume {
+            let mut source = index;
+            source[axis] = indices[index[axis]];
+            data.push(self.data[unchecked_offset(&self.strides, self.offset, &source)]);
+            bump_index(&mut index, &dims);
+        }
+
+        Ok(ArrayBase { dims, data })
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> ArrayMut<'base, T, NDIMS> {
+    /// Scatter `values` to the positions in `indices` along `axis`, broadcasting
+    /// `values` over the dims `take` would produce. Repeated indices keep the last value.
+    pub fn put<const NDIMS2: usize>(
+        &mut self,
+        indices: &Array<usize, 1>,
+        values: &Array<T, NDIMS2>,
+        axis: usize,
+    ) -> ArrResult<()> {
+        check_axis::<NDIMS>(axis)?;
+        let indices = checked_indices(indices, axis, self.dims[axis])?;
+
+        let mut dims = self.dims;
+        dims[axis] = indices.len();
+        let values = values.broadcast_to(dims).map_err(|_| Error::Broadcast {
+            dims1: dims.to_vec(),
+            dims2: values.dims.to_vec(),
+        })?;
+
+        let mut index = [0; NDIMS];
+        for &value in values.iter() {
+            let mut dest = index;
+            dest[axis] = indices[index[axis]];
+            self.data[unchecked_offset(&self.strides, self.offset, &dest)] = value;
+            bump_index(&mut index, &dims);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Element, const NDIMS: usize> ArrayBase<T, NDIMS> {
+    /// Scatter `values` to the positions in `indices` along `axis`, broadcasting
+    /// `values` over the dims `take` would produce. Repeated indices keep the last value.
+    pub fn put<const NDIMS2: usize>(
+        &mut self,
+        indices: &Array<usize, 1>,
+        values: &Array<T, NDIMS2>,
+        axis: usize,
+    ) -> ArrResult<()> {
+        self.view_mut().put(indices, values, axis)
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> Index<[usize; NDIMS]> for Array<'base, T, NDIMS> {
+    type Output = T;
+
+    fn index(&self, index: [usize; NDIMS]) -> &T {
+        self.get(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> Index<[usize; NDIMS]> for ArrayMut<'base, T, NDIMS> {
+    type Output = T;
+
+    fn index(&self, index: [usize; NDIMS]) -> &T {
+        self.get(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> IndexMut<[usize; NDIMS]> for ArrayMut<'base, T, NDIMS> {
+    fn index_mut(&mut self, index: [usize; NDIMS]) -> &mut T {
+        self.get_mut(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<T: Element, const NDIMS: usize> Index<[usize; NDIMS]> for ArrayBase<T, NDIMS> {
+    type Output = T;
+
+    fn index(&self, index: [usize; NDIMS]) -> &T {
+        self.get(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<T: Element, const NDIMS: usize> IndexMut<[usize; NDIMS]> for ArrayBase<T, NDIMS> {
+    fn index_mut(&mut self, index: [usize; NDIMS]) -> &mut T {
+        self.get_mut(index).unwrap_or_else(|err| panic!("{}", err))
+    }
+}
@@ -0,0 +1,86 @@
+use crate::array::{Array, Element};
+
+/// Iterator over the `data` offsets of a strided view, innermost dim first
+#[derive(Clone, Debug)]
+pub(crate) struct Offsets<const NDIMS: usize> {
+    dims: [usize; NDIMS],
+    strides: [isize; NDIMS],
+    index: [usize; NDIMS],
+    offset: isize,
+    remaining: usize,
+}
+
+impl<const NDIMS: usize> Offsets<NDIMS> {
+    pub(crate) fn new(dims: [usize; NDIMS], strides: [isize; NDIMS], offset: usize) -> Self {
+        Self {
+            dims,
+            strides,
+            index: [0; NDIMS],
+            offset: offset as isize,
+            remaining: dims.iter().product(),
+        }
+    }
+}
+
+impl<const NDIMS: usize> Iterator for Offsets<NDIMS> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let current = self.offset as usize;
+
+        // odometer style: bump the innermost dim, carrying outwards
+        for axis in 0..NDIMS {
+            self.index[axis] += 1;
+            self.offset += self.strides[axis];
+            if self.index[axis] < self.dims[axis] {
+                break;
+            }
+            self.index[axis] = 0;
+            self.offset -= self.strides[axis] * self.dims[axis] as isize;
+        }
+
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<const NDIMS: usize> ExactSizeIterator for Offsets<NDIMS> {}
+
+/// Iterator over the values of an `Array`, innermost dim first
+#[derive(Clone, Debug)]
+pub struct Iter<'base, T: Element, const NDIMS: usize> {
+    offsets: Offsets<NDIMS>,
+    data: &'base [T],
+}
+
+impl<'base, T: Element, const NDIMS: usize> Iter<'base, T, NDIMS> {
+    pub(crate) fn new(array: &Array<'base, T, NDIMS>) -> Self {
+        Self {
+            offsets: array.offsets(),
+            data: array.data,
+        }
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> Iterator for Iter<'base, T, NDIMS> {
+    type Item = &'base T;
+
+    fn next(&mut self) -> Option<&'base T> {
+        let data = self.data;
+        self.offsets.next().map(|offset| &data[offset])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> ExactSizeIterator for Iter<'base, T, NDIMS> {}
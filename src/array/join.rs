@@ -0,0 +1,95 @@
+use crate::array::{check_axis, ArrResult, Array, ArrayBase, Element, Error};
+
+impl<T: Element, const NDIMS: usize> ArrayBase<T, NDIMS> {
+    /// Join `arrays` end to end along `axis`. All other dims must match.
+    pub fn concatenate(arrays: &[Array<T, NDIMS>], axis: usize) -> ArrResult<Self> {
+        check_axis::<NDIMS>(axis)?;
+        let (first, rest) = arrays.split_first().ok_or(Error::JoinEmpty)?;
+
+        let mut dims = first.dims;
+        for array in rest {
+            let mismatched = (0..NDIMS).any(|i| i != axis && array.dims[i] != first.dims[i]);
+            if mismatched {
+                return Err(Error::JoinMismatchedDims {
+                    dims1: first.dims.to_vec(),
+                    dims2: array.dims.to_vec(),
+                    axis,
+                });
+            }
+            dims[axis] += array.dims[axis];
+        }
+
+        // each array contributes one chunk of its inner dims per step of the outer dims
+        let outer = dims[axis + 1..].iter().product::<usize>();
+        let chunks = arrays
+            .iter()
+            .map(|array| array.dims[..=axis].iter().product::<usize>())
+            .collect::<Vec<_>>();
+
+        let mut iters = arrays.iter().map(|array| array.iter()).collect::<Vec<_>>();
+        let mut data = Vec::with_capacity(dims.iter().product());
+        for _ in 0..outer {
+            for (iter, &chunk) in iters.iter_mut().zip(chunks.iter()) {
+                data.extend(iter.take(chunk).copied());
+            }
+        }
+
+        Ok(Self { dims, data })
+    }
+
+    /// Join `arrays` along a new dim inserted at `axis`. All dims must match.
+    pub fn stack(
+        arrays: &[Array<T, NDIMS>],
+        axis: usize,
+    ) -> ArrResult<ArrayBase<T, { NDIMS + 1 }>> {
+        let first = arrays.first().ok_or(Error::JoinEmpty)?;
+        if let Some(array) = arrays.iter().find(|array| array.dims != first.dims) {
+            return Err(Error::JoinMismatchedDims {
+                dims1: first.dims.to_vec(),
+                dims2: array.dims.to_vec(),
+                axis,
+            });
+        }
+
+        let expanded = arrays
+            .iter()
+            .map(|array| array.expand_dims(axis))
+            .collect::<ArrResult<Vec<_>>>()?;
+
+        ArrayBase::concatenate(&expanded, axis)
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Split into `sections` equal views along `axis`
+    pub fn split(&self, sections: usize, axis: usize) -> ArrResult<Vec<Self>> {
+        check_axis::<NDIMS>(axis)?;
+        let len = self.dims[axis];
+        if sections == 0 || !len.is_multiple_of(sections) {
+            return Err(Error::Split { len, sections });
+        }
+
+        self.array_split(sections, axis)
+    }
+
+    /// Split into `sections` views along `axis`. When the len doesn't divide
+    /// evenly, the first `len % sections` views get one extra value.
+    pub fn array_split(&self, sections: usize, axis: usize) -> ArrResult<Vec<Self>> {
+        check_axis::<NDIMS>(axis)?;
+        let len = self.dims[axis];
+        if sections == 0 || sections > len {
+            return Err(Error::Split { len, sections });
+        }
+
+        let (width, extra) = (len / sections, len % sections);
+        let mut start = 0;
+        (0..sections)
+            .map(|section| {
+                let stop = start + width + (section < extra) as usize;
+                let view = self.slice_axis(axis, start, stop, 1);
+                start = stop;
+                view
+            })
+            .collect()
+    }
+}
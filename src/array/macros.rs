@@ -3,6 +3,9 @@ macro_rules! arrs {
     ( let $name:ident = View($base:expr) ) => {
         let $name = crate::array::Array::from_base(&$base);
     };
+    ( let $name:ident = ViewMut($base:expr) ) => {
+        let mut $name = crate::array::ArrayMut::from_base(&mut $base);
+    };
     ( let $name:ident = Array($dims:expr, $data:expr) ) => {
         let $name = crate::array::ArrayBase::new($dims, $data)?;
         arrs!(let $name = View($name));
@@ -12,22 +15,21 @@ macro_rules! arrs {
         arrs!(let $name = View($name));
     };
 
-    // using macros for operations because regular functions that are type checked
-    // with experimental features crash the compiler due to a bug :(
+    // unlike the operator impls in `ops`, these propagate broadcast errors with `?`
     ( let $name:ident = add($arr1:expr, $arr2:expr) ) => {
-        let $name = $arr1.broadcast_combine($arr2, |a, b| a + b)?;
+        let $name = $arr1.try_add($arr2)?;
         arrs!(let $name = View($name));
     };
     ( let $name:ident = sub($arr1:expr, $arr2:expr) ) => {
-        let $name = $arr1.broadcast_combine($arr2, |a, b| a - b)?;
+        let $name = $arr1.try_sub($arr2)?;
         arrs!(let $name = View($name));
     };
     ( let $name:ident = mul($arr1:expr, $arr2:expr) ) => {
-        let $name = $arr1.broadcast_combine($arr2, |a, b| a * b)?;
+        let $name = $arr1.try_mul($arr2)?;
         arrs!(let $name = View($name));
     };
     ( let $name:ident = div($arr1:expr, $arr2:expr) ) => {
-        let $name = $arr1.broadcast_combine($arr2, |a, b| a / b)?;
+        let $name = $arr1.try_div($arr2)?;
         arrs!(let $name = View($name));
     };
     ( let $name:ident = matmul($arr1:expr, $arr2:expr) ) => {
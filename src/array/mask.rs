@@ -0,0 +1,99 @@
+use crate::array::{bump_index, ArrResult, Array, ArrayBase, ArrayMut, Element, Error};
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Collect the values where `mask` is `true`, broadcasting `mask` over these dims
+    pub fn masked_select<const NDIMS2: usize>(
+        &self,
+        mask: &Array<bool, NDIMS2>,
+    ) -> ArrResult<ArrayBase<T, 1>> {
+        let mask = mask.broadcast_to(self.dims).map_err(|_| Error::Broadcast {
+            dims1: self.dims.to_vec(),
+            dims2: mask.dims.to_vec(),
+        })?;
+
+        let data: Vec<T> = self
+            .iter()
+            .zip(mask.iter())
+            .filter_map(|(&x, &keep)| if keep { Some(x) } else { None })
+            .collect();
+
+        Ok(ArrayBase {
+            dims: [data.len()],
+            data,
+        })
+    }
+}
+
+// A value counts as nonzero when it differs from `T::default()`, which is
+// `false` for `bool` and zero for the numeric types.
+
+impl<'base, T: Element + Default, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Indices of the nonzero values, one index array per dim, innermost first
+    pub fn nonzero(&self) -> [ArrayBase<usize, 1>; NDIMS] {
+        let mut indices = [(); NDIMS].map(|_| Vec::new());
+        let mut index = [0; NDIMS];
+        for &x in self.iter() {
+            if x != T::default() {
+                for (axis_indices, &i) in indices.iter_mut().zip(&index) {
+                    axis_indices.push(i);
+                }
+            }
+            bump_index(&mut index, &self.dims);
+        }
+
+        indices.map(|data| ArrayBase {
+            dims: [data.len()],
+            data,
+        })
+    }
+
+    /// Indices of the nonzero values, one per row. The result has dims
+    /// `[NDIMS, count]`, so each row is an index, innermost first.
+    pub fn argwhere(&self) -> ArrayBase<usize, 2> {
+        let mut data = vec![];
+        let mut index = [0; NDIMS];
+        for &x in self.iter() {
+            if x != T::default() {
+                data.extend_from_slice(&index);
+            }
+            bump_index(&mut index, &self.dims);
+        }
+
+        ArrayBase {
+            dims: [NDIMS, data.len() / NDIMS],
+            data,
+        }
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> ArrayMut<'base, T, NDIMS> {
+    /// Set the values where `mask` is `true` to `value`, broadcasting `mask` over these dims
+    pub fn masked_fill<const NDIMS2: usize>(
+        &mut self,
+        mask: &Array<bool, NDIMS2>,
+        value: T,
+    ) -> ArrResult<()> {
+        let mask = mask.broadcast_to(self.dims).map_err(|_| Error::Broadcast {
+            dims1: self.dims.to_vec(),
+            dims2: mask.dims.to_vec(),
+        })?;
+
+        for (offset, &fill) in self.offsets().zip(mask.iter()) {
+            if fill {
+                self.data[offset] = value;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Element, const NDIMS: usize> ArrayBase<T, NDIMS> {
+    /// Set the values where `mask` is `true` to `value`, broadcasting `mask` over these dims
+    pub fn masked_fill<const NDIMS2: usize>(
+        &mut self,
+        mask: &Array<bool, NDIMS2>,
+        value: T,
+    ) -> ArrResult<()> {
+        self.view_mut().masked_fill(mask, value)
+    }
+}
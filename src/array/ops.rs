@@ -0,0 +1,223 @@
+use crate::array::{max_const, ArrResult, ArrType, Array, ArrayBase, ArrayMut, Element, Error};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+// Operators between arrays broadcast like `broadcast_combine`, and panic with the
+// `Error::Broadcast` message when the dims don't line up. The `try_*` methods
+// return the error instead.
+
+macro_rules! impl_op {
+    { $op:ident, $method:ident, $try_method:ident, $symbol:tt } => {
+        impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
+            /// Elementwise operation using array broadcasting
+            pub fn $try_method<const NDIMS2: usize>(
+                &self,
+                other: &Array<T, NDIMS2>,
+            ) -> ArrResult<ArrayBase<T, { max_const(NDIMS, NDIMS2) }>> {
+                self.broadcast_combine(other, |a, b| a $symbol b)
+            }
+        }
+
+        impl<T: ArrType, const NDIMS: usize> ArrayBase<T, NDIMS> {
+            /// Elementwise operation using array broadcasting
+            pub fn $try_method<const NDIMS2: usize>(
+                &self,
+                other: &Array<T, NDIMS2>,
+            ) -> ArrResult<ArrayBase<T, { max_const(NDIMS, NDIMS2) }>> {
+                self.view().$try_method(other)
+            }
+        }
+
+        impl<'a, 'b, T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&Array<'b, T, NDIMS2>>
+            for &Array<'a, T, NDIMS>
+        where
+            [(); max_const(NDIMS, NDIMS2)]: ,
+        {
+            type Output = ArrayBase<T, { max_const(NDIMS, NDIMS2) }>;
+
+            fn $method(self, other: &Array<'b, T, NDIMS2>) -> Self::Output {
+                self.$try_method(other).unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+
+        impl<'a, T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&ArrayBase<T, NDIMS2>>
+            for &Array<'a, T, NDIMS>
+        where
+            [(); max_const(NDIMS, NDIMS2)]: ,
+        {
+            type Output = ArrayBase<T, { max_const(NDIMS, NDIMS2) }>;
+
+            fn $method(self, other: &ArrayBase<T, NDIMS2>) -> Self::Output {
+                self.$method(&other.view())
+            }
+        }
+
+        impl<'b, T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&Array<'b, T, NDIMS2>>
+            for &ArrayBase<T, NDIMS>
+        where
+            [(); max_const(NDIMS, NDIMS2)]: ,
+        {
+            type Output = ArrayBase<T, { max_const(NDIMS, NDIMS2) }>;
+
+            fn $method(self, other: &Array<'b, T, NDIMS2>) -> Self::Output {
+                (&self.view()).$method(other)
+            }
+        }
+
+        impl<T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&ArrayBase<T, NDIMS2>>
+            for &ArrayBase<T, NDIMS>
+        where
+            [(); max_const(NDIMS, NDIMS2)]: ,
+        {
+            type Output = ArrayBase<T, { max_const(NDIMS, NDIMS2) }>;
+
+            fn $method(self, other: &ArrayBase<T, NDIMS2>) -> Self::Output {
+                (&self.view()).$method(&other.view())
+            }
+        }
+
+        impl<'a, T: ArrType, const NDIMS: usize> $op<T> for &Array<'a, T, NDIMS> {
+            type Output = ArrayBase<T, NDIMS>;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                self.map(|x| x $symbol scalar)
+            }
+        }
+
+        impl<T: ArrType, const NDIMS: usize> $op<T> for &ArrayBase<T, NDIMS> {
+            type Output = ArrayBase<T, NDIMS>;
+
+            fn $method(self, scalar: T) -> Self::Output {
+                self.view().map(|x| x $symbol scalar)
+            }
+        }
+    };
+}
+
+impl_op! { Add, add, try_add, + }
+impl_op! { Sub, sub, try_sub, - }
+impl_op! { Mul, mul, try_mul, * }
+impl_op! { Div, div, try_div, / }
+
+// scalars on the left need an impl per type, since `T` can't be the `Self` of a foreign trait
+macro_rules! impl_scalar_op {
+    { $op:ident, $method:ident, $symbol:tt; $($inner_type:ty),* } => {
+        $(
+            impl<'a, const NDIMS: usize> $op<&Array<'a, $inner_type, NDIMS>> for $inner_type {
+                type Output = ArrayBase<$inner_type, NDIMS>;
+
+                fn $method(self, array: &Array<'a, $inner_type, NDIMS>) -> Self::Output {
+                    array.map(|x| self $symbol x)
+                }
+            }
+
+            impl<const NDIMS: usize> $op<&ArrayBase<$inner_type, NDIMS>> for $inner_type {
+                type Output = ArrayBase<$inner_type, NDIMS>;
+
+                fn $method(self, array: &ArrayBase<$inner_type, NDIMS>) -> Self::Output {
+                    array.view().map(|x| self $symbol x)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_op! { Add, add, +; u8, i8, i16, i32, f32, f64 }
+impl_scalar_op! { Sub, sub, -; u8, i8, i16, i32, f32, f64 }
+impl_scalar_op! { Mul, mul, *; u8, i8, i16, i32, f32, f64 }
+impl_scalar_op! { Div, div, /; u8, i8, i16, i32, f32, f64 }
+
+impl<'base, T: Element, const NDIMS: usize> ArrayMut<'base, T, NDIMS> {
+    /// Replace each value with `f(value, other_value)`, broadcasting `other` over these dims
+    pub fn combine_assign<U: Element, const NDIMS2: usize, F: Fn(T, U) -> T>(
+        &mut self,
+        other: &Array<U, NDIMS2>,
+        combinator: F,
+    ) -> ArrResult<()> {
+        let other = other
+            .broadcast_to(self.dims)
+            .map_err(|_| Error::Broadcast {
+                dims1: self.dims.to_vec(),
+                dims2: other.dims.to_vec(),
+            })?;
+
+        for (offset, &x) in self.offsets().zip(other.iter()) {
+            self.data[offset] = combinator(self.data[offset], x);
+        }
+        Ok(())
+    }
+}
+
+// Compound assignment broadcasts the right side into the left side's dims,
+// which never grow. Like the operators above, these panic where the `try_*`
+// methods return `Error::Broadcast`.
+
+macro_rules! impl_assign_op {
+    { $op:ident, $method:ident, $try_method:ident, $symbol:tt } => {
+        impl<'base, T: ArrType, const NDIMS: usize> ArrayMut<'base, T, NDIMS> {
+            /// Elementwise operation in place, broadcasting `other` over these dims
+            pub fn $try_method<const NDIMS2: usize>(&mut self, other: &Array<T, NDIMS2>) -> ArrResult<()> {
+                self.combine_assign(other, |a, b| a $symbol b)
+            }
+        }
+
+        impl<T: ArrType, const NDIMS: usize> ArrayBase<T, NDIMS> {
+            /// Elementwise operation in place, broadcasting `other` over these dims
+            pub fn $try_method<const NDIMS2: usize>(&mut self, other: &Array<T, NDIMS2>) -> ArrResult<()> {
+                self.view_mut().$try_method(other)
+            }
+        }
+
+        impl<'a, 'b, T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&Array<'b, T, NDIMS2>>
+            for ArrayMut<'a, T, NDIMS>
+        {
+            fn $method(&mut self, other: &Array<'b, T, NDIMS2>) {
+                self.$try_method(other).unwrap_or_else(|err| panic!("{}", err))
+            }
+        }
+
+        impl<'a, T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&ArrayBase<T, NDIMS2>>
+            for ArrayMut<'a, T, NDIMS>
+        {
+            fn $method(&mut self, other: &ArrayBase<T, NDIMS2>) {
+                self.$method(&other.view())
+            }
+        }
+
+        impl<'b, T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&Array<'b, T, NDIMS2>>
+            for ArrayBase<T, NDIMS>
+        {
+            fn $method(&mut self, other: &Array<'b, T, NDIMS2>) {
+                self.view_mut().$method(other)
+            }
+        }
+
+        impl<T: ArrType, const NDIMS: usize, const NDIMS2: usize> $op<&ArrayBase<T, NDIMS2>>
+            for ArrayBase<T, NDIMS>
+        {
+            fn $method(&mut self, other: &ArrayBase<T, NDIMS2>) {
+                self.view_mut().$method(&other.view())
+            }
+        }
+
+        impl<'a, T: ArrType, const NDIMS: usize> $op<T> for ArrayMut<'a, T, NDIMS> {
+            fn $method(&mut self, scalar: T) {
+                for offset in self.offsets() {
+                    self.data[offset] = self.data[offset] $symbol scalar;
+                }
+            }
+        }
+
+        impl<T: ArrType, const NDIMS: usize> $op<T> for ArrayBase<T, NDIMS> {
+            fn $method(&mut self, scalar: T) {
+                for x in self.data.iter_mut() {
+                    *x = *x $symbol scalar;
+                }
+            }
+        }
+    };
+}
+
+impl_assign_op! { AddAssign, add_assign, try_add_assign, + }
+impl_assign_op! { SubAssign, sub_assign, try_sub_assign, - }
+impl_assign_op! { MulAssign, mul_assign, try_mul_assign, * }
+impl_assign_op! { DivAssign, div_assign, try_div_assign, / }
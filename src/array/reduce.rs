@@ -0,0 +1,224 @@
+use crate::array::{
+    check_axis, remove_axis, ArrResult, ArrType, Array, ArrayBase, Element, Error, Offsets,
+    Summation,
+};
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Apply `f` to every 1-D lane along `axis`, keeping `axis` with len 1
+    pub(crate) fn reduce_axis_keepdims<R: Element, F: Fn(Array<'base, T, 1>) -> ArrResult<R>>(
+        &self,
+        axis: usize,
+        f: F,
+    ) -> ArrResult<ArrayBase<R, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        let mut dims = self.dims;
+        dims[axis] = 1;
+
+        // with `axis` at len 1, the offsets are the first value of each lane
+        let data = Offsets::new(dims, self.strides, self.offset)
+            .map(|offset| {
+                f(Array {
+                    dims: [self.dims[axis]],
+                    strides: [self.strides[axis]],
+                    offset,
+                    data: self.data,
+                })
+            })
+            .collect::<ArrResult<_>>()?;
+
+        Ok(ArrayBase { dims, data })
+    }
+
+    /// Apply `f` to every 1-D lane along `axis`, removing `axis`
+    pub(crate) fn reduce_axis<R: Element, F: Fn(Array<'base, T, 1>) -> ArrResult<R>>(
+        &self,
+        axis: usize,
+        f: F,
+    ) -> ArrResult<ArrayBase<R, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        let ArrayBase { dims, data } = self.reduce_axis_keepdims(axis, f)?;
+
+        Ok(ArrayBase {
+            dims: remove_axis(&dims, axis),
+            data,
+        })
+    }
+}
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Replace every 1-D lane along `axis` with `f` of it, which must have len `len`
+    pub(crate) fn map_axis<R: Element, F: Fn(Array<'base, T, 1>) -> Vec<R>>(
+        &self,
+        axis: usize,
+        len: usize,
+        f: F,
+    ) -> ArrResult<ArrayBase<R, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        let mut dims = self.dims;
+        dims[axis] = 1;
+
+        // lay the lanes end to end, which makes `axis` innermost
+        let mut data = Vec::with_capacity(dims.iter().product::<usize>() * len);
+        for offset in Offsets::new(dims, self.strides, self.offset) {
+            data.extend(f(Array {
+                dims: [self.dims[axis]],
+                strides: [self.strides[axis]],
+                offset,
+                data: self.data,
+            }));
+        }
+
+        let mut lanes_dims = [0; NDIMS];
+        let mut axes = [0; NDIMS];
+        lanes_dims[0] = len;
+        for (i, (lanes_axis, &dim)) in axes.iter_mut().zip(&self.dims).enumerate() {
+            if i != axis {
+                *lanes_axis = if i < axis { i + 1 } else { i };
+                lanes_dims[*lanes_axis] = dim;
+            }
+        }
+
+        // then move `axis` back where it was
+        let lanes = ArrayBase {
+            dims: lanes_dims,
+            data,
+        };
+        Ok(lanes.view().permute_axes(axes)?.into_base())
+    }
+}
+
+// Each reduction is written once over an iterator of values, then shared by
+// the `axis`, `keepdims` and whole array methods below.
+
+fn sum_values<'a, T: ArrType + 'a>(values: impl Iterator<Item = &'a T>) -> ArrResult<T> {
+    sum_values_with(values, T::SUMMATION)
+}
+
+fn sum_values_with<'a, T: ArrType + 'a>(
+    values: impl Iterator<Item = &'a T>,
+    summation: Summation,
+) -> ArrResult<T> {
+    Ok(summation.sum(values.copied()))
+}
+
+fn prod_values<'a, T: ArrType + 'a>(values: impl Iterator<Item = &'a T>) -> ArrResult<T> {
+    Ok(values.fold(T::ONE, |acc, &x| acc * x))
+}
+
+fn mean_values<'a, T: ArrType + 'a>(values: impl ExactSizeIterator<Item = &'a T>) -> ArrResult<T> {
+    mean_values_with(values, T::SUMMATION)
+}
+
+/// Adds up in `f64`, so integer sums can't overflow, then converts back
+fn mean_values_with<'a, T: ArrType + 'a>(
+    values: impl ExactSizeIterator<Item = &'a T>,
+    summation: Summation,
+) -> ArrResult<T> {
+    match values.len() {
+        0 => Err(Error::ReduceEmpty),
+        len => {
+            let sum: f64 = summation.sum(values.map(|&x| x.into()));
+            Ok(T::from_f64(sum / len as f64))
+        }
+    }
+}
+
+/// Whether `x` is unordered even against itself, like NaN
+pub(crate) fn is_nan<T: PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+/// Whether `acc` beats `x` under `wins`, except that NaN beats anything
+pub(crate) fn keeps<T: PartialOrd, F: Fn(&T, &T) -> bool>(acc: &T, x: &T, wins: F) -> bool {
+    is_nan(acc) || (!is_nan(x) && wins(acc, x))
+}
+
+fn min_values<'a, T: Element + PartialOrd + 'a>(
+    mut values: impl Iterator<Item = &'a T>,
+) -> ArrResult<T> {
+    let first = *values.next().ok_or(Error::ReduceEmpty)?;
+    Ok(values.fold(first, |acc, &x| {
+        if keeps(&acc, &x, |acc, x| acc <= x) {
+            acc
+        } else {
+            x
+        }
+    }))
+}
+
+fn max_values<'a, T: Element + PartialOrd + 'a>(
+    mut values: impl Iterator<Item = &'a T>,
+) -> ArrResult<T> {
+    let first = *values.next().ok_or(Error::ReduceEmpty)?;
+    Ok(values.fold(first, |acc, &x| {
+        if keeps(&acc, &x, |acc, x| acc >= x) {
+            acc
+        } else {
+            x
+        }
+    }))
+}
+
+macro_rules! impl_reduce {
+    { [$($bound:tt)+], $values:ident, $name:literal, $method:ident, $keepdims:ident, $all:ident } => {
+        impl<'base, T: $($bound)+, const NDIMS: usize> Array<'base, T, NDIMS> {
+            #[doc = concat!("Reduce `axis` to its ", $name, ", removing it")]
+            pub fn $method(&self, axis: usize) -> ArrResult<ArrayBase<T, { NDIMS - 1 }>>
+            where
+                [(); NDIMS - 2]: ,
+            {
+                self.reduce_axis(axis, |lane| $values(lane.iter()))
+            }
+
+            #[doc = concat!("Reduce `axis` to its ", $name, ", keeping it with len 1 so the result broadcasts against `self`")]
+            pub fn $keepdims(&self, axis: usize) -> ArrResult<ArrayBase<T, NDIMS>> {
+                self.reduce_axis_keepdims(axis, |lane| $values(lane.iter()))
+            }
+
+            #[doc = concat!("Reduce every value to their ", $name)]
+            pub fn $all(&self) -> ArrResult<T> {
+                $values(self.iter())
+            }
+        }
+    };
+}
+
+macro_rules! impl_reduce_with {
+    { $values:ident, $name:literal, $method:ident, $keepdims:ident, $all:ident } => {
+        impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
+            #[doc = concat!("Reduce `axis` to its ", $name, " added up with `summation`, removing it")]
+            pub fn $method(&self, axis: usize, summation: Summation) -> ArrResult<ArrayBase<T, { NDIMS - 1 }>>
+            where
+                [(); NDIMS - 2]: ,
+            {
+                self.reduce_axis(axis, |lane| $values(lane.iter(), summation))
+            }
+
+            #[doc = concat!("Reduce `axis` to its ", $name, " added up with `summation`, keeping it with len 1")]
+            pub fn $keepdims(&self, axis: usize, summation: Summation) -> ArrResult<ArrayBase<T, NDIMS>> {
+                self.reduce_axis_keepdims(axis, |lane| $values(lane.iter(), summation))
+            }
+
+            #[doc = concat!("Reduce every value to their ", $name, " added up with `summation`")]
+            pub fn $all(&self, summation: Summation) -> ArrResult<T> {
+                $values(self.iter(), summation)
+            }
+        }
+    };
+}
+
+// `sum` and `mean` add up with `ArrType::SUMMATION`, which the `_with`
+// variants override. Integer means round towards zero. `mean`, `min` and
+// `max` have no identity value, so they fail with `Error::ReduceEmpty` on
+// empty lanes. `min` and `max` propagate NaN.
+
+impl_reduce! { [ArrType], sum_values, "sum", sum, sum_keepdims, sum_all }
+impl_reduce! { [ArrType], prod_values, "product", prod, prod_keepdims, prod_all }
+impl_reduce! { [ArrType], mean_values, "mean", mean, mean_keepdims, mean_all }
+impl_reduce! { [Element + PartialOrd], min_values, "minimum", min, min_keepdims, min_all }
+impl_reduce! { [Element + PartialOrd], max_values, "maximum", max, max_keepdims, max_all }
+
+impl_reduce_with! { sum_values_with, "sum", sum_with, sum_keepdims_with, sum_all_with }
+impl_reduce_with! { mean_values_with, "mean", mean_with, mean_keepdims_with, mean_all_with }
@@ -0,0 +1,69 @@
+use crate::array::{check_axis, contiguous_strides, remove_axis, ArrResult, Array, Element, Error};
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// View the same values with new dims, without copying.
+    /// Only contiguous views can be reshaped, use `into_base` first otherwise.
+    pub fn reshape<const M: usize>(&self, dims: [usize; M]) -> ArrResult<Array<'base, T, M>> {
+        let (volume, len) = (dims.iter().product(), self.dims.iter().product());
+        if M == 0 {
+            return Err(Error::ShapeZeroDims);
+        } else if volume != len {
+            return Err(Error::ShapeDataMisalignment { volume, len });
+        } else if !self.is_contiguous() {
+            return Err(Error::NotContiguous {
+                dims: self.dims.to_vec(),
+                strides: self.strides.to_vec(),
+            });
+        }
+
+        Ok(Array {
+            dims,
+            strides: contiguous_strides(&dims),
+            offset: self.offset,
+            data: self.data,
+        })
+    }
+
+    /// View the values as a single dim, without copying
+    pub fn flatten(&self) -> ArrResult<Array<'base, T, 1>> {
+        self.reshape([self.dims.iter().product()])
+    }
+
+    /// Remove `axis`, which must have a len of 1
+    pub fn squeeze_axis(&self, axis: usize) -> ArrResult<Array<'base, T, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        check_axis::<NDIMS>(axis)?;
+        let len = self.dims[axis];
+        if len != 1 {
+            return Err(Error::SqueezeLenNotOne { axis, len });
+        }
+
+        Ok(Array {
+            dims: remove_axis(&self.dims, axis),
+            strides: remove_axis(&self.strides, axis),
+            offset: self.offset,
+            data: self.data,
+        })
+    }
+
+    /// Insert a new dim of len 1 at `axis`
+    pub fn expand_dims(&self, axis: usize) -> ArrResult<Array<'base, T, { NDIMS + 1 }>> {
+        check_axis::<{ NDIMS + 1 }>(axis)?;
+
+        let (mut dims, mut strides) = ([1; NDIMS + 1], [0; NDIMS + 1]);
+        for i in 0..NDIMS {
+            let to = if i < axis { i } else { i + 1 };
+            dims[to] = self.dims[i];
+            strides[to] = self.strides[i];
+        }
+
+        Ok(Array {
+            dims,
+            strides,
+            offset: self.offset,
+            data: self.data,
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use crate::array::{check_axis, ArrResult, ArrType, Array, ArrayBase, Error};
+
+impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Running sum along `axis`
+    pub fn cumsum(&self, axis: usize) -> ArrResult<ArrayBase<T, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        self.map_axis(axis, self.dims[axis], |lane| {
+            lane.iter()
+                .scan(T::ZERO, |acc, &x| {
+                    *acc = *acc + x;
+                    Some(*acc)
+                })
+                .collect()
+        })
+    }
+
+    /// Running product along `axis`
+    pub fn cumprod(&self, axis: usize) -> ArrResult<ArrayBase<T, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        self.map_axis(axis, self.dims[axis], |lane| {
+            lane.iter()
+                .scan(T::ONE, |acc, &x| {
+                    *acc = *acc * x;
+                    Some(*acc)
+                })
+                .collect()
+        })
+    }
+
+    /// The `n`th discrete difference along `axis`, which shrinks it by `n`,
+    /// down to len 0 once `n` reaches its len
+    pub fn diff(&self, n: usize, axis: usize) -> ArrResult<ArrayBase<T, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        self.map_axis(axis, self.dims[axis].saturating_sub(n), |lane| {
+            let mut values: Vec<T> = lane.iter().copied().collect();
+            for _ in 0..n.min(values.len()) {
+                values = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+            }
+            values
+        })
+    }
+}
+
+impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS>
+where
+    f64: From<T>,
+{
+    /// Rate of change along `axis` with unit spacing, promoted to `f64`.
+    /// Central differences inside, one-sided differences at either end.
+    pub fn gradient(&self, axis: usize) -> ArrResult<ArrayBase<f64, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        let len = self.dims[axis];
+        if len < 2 {
+            return Err(Error::Gradient { axis, len });
+        }
+
+        let values = self.as_type::<f64>();
+        values.view().map_axis(axis, len, |lane| {
+            let x = lane.contiguous();
+            (0..len)
+                .map(|i| match i {
+                    0 => x[1] - x[0],
+                    i if i == len - 1 => x[i] - x[i - 1],
+                    i => (x[i + 1] - x[i - 1]) / 2.0,
+                })
+                .collect()
+        })
+    }
+}
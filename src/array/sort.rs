@@ -0,0 +1,94 @@
+use crate::array::reduce::{is_nan, keeps};
+use crate::array::{check_axis, ArrResult, Array, ArrayBase, Element, Error};
+use core::cmp::Ordering;
+
+// NaN policy: `argmin` and `argmax` pick the first NaN, just as `min` and
+// `max` propagate it, while sorting orders NaN after every other value.
+
+/// Total order for sorting, with NaN after every other value
+fn nan_last<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| is_nan(a).cmp(&is_nan(b)))
+}
+
+/// Position of the first value that `wins` against all the others
+fn arg_values<'a, T: PartialOrd + 'a, F: Fn(&T, &T) -> bool + Copy>(
+    values: impl Iterator<Item = &'a T>,
+    wins: F,
+) -> ArrResult<usize> {
+    let mut values = values.enumerate();
+    let first = values.next().ok_or(Error::ReduceEmpty)?;
+    let (arg, _) = values.fold(first, |(i, acc), (j, x)| {
+        if keeps(acc, x, wins) {
+            (i, acc)
+        } else {
+            (j, x)
+        }
+    });
+
+    Ok(arg)
+}
+
+/// Index of the value at `position` in iteration order, innermost first
+fn unravel<const NDIMS: usize>(mut position: usize, dims: &[usize; NDIMS]) -> [usize; NDIMS] {
+    let mut index = [0; NDIMS];
+    for (i, &len) in index.iter_mut().zip(dims) {
+        *i = position % len;
+        position /= len;
+    }
+    index
+}
+
+impl<'base, T: Element + PartialOrd, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Position of the minimum along `axis`, removing it. Ties go to the first.
+    pub fn argmin(&self, axis: usize) -> ArrResult<ArrayBase<usize, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        self.reduce_axis(axis, |lane| arg_values(lane.iter(), |acc, x| acc <= x))
+    }
+
+    /// Position of the maximum along `axis`, removing it. Ties go to the first.
+    pub fn argmax(&self, axis: usize) -> ArrResult<ArrayBase<usize, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        self.reduce_axis(axis, |lane| arg_values(lane.iter(), |acc, x| acc >= x))
+    }
+
+    /// Index of the minimum of every value. Ties go to the first.
+    pub fn argmin_all(&self) -> ArrResult<[usize; NDIMS]> {
+        arg_values(self.iter(), |acc, x| acc <= x).map(|position| unravel(position, &self.dims))
+    }
+
+    /// Index of the maximum of every value. Ties go to the first.
+    pub fn argmax_all(&self) -> ArrResult<[usize; NDIMS]> {
+        arg_values(self.iter(), |acc, x| acc >= x).map(|position| unravel(position, &self.dims))
+    }
+
+    /// Positions that would sort each lane along `axis`. The sort is stable.
+    pub fn argsort(&self, axis: usize) -> ArrResult<ArrayBase<usize, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        self.map_axis(axis, self.dims[axis], |lane| {
+            let values: Vec<T> = lane.iter().copied().collect();
+            let mut positions: Vec<usize> = (0..values.len()).collect();
+            positions.sort_by(|&i, &j| nan_last(&values[i], &values[j]));
+            positions
+        })
+    }
+
+    /// Sorted copy along the innermost dim
+    pub fn sort(&self) -> ArrayBase<T, NDIMS> {
+        self.sort_axis(0).unwrap()
+    }
+
+    /// Sorted copy along `axis`. The sort is stable.
+    pub fn sort_axis(&self, axis: usize) -> ArrResult<ArrayBase<T, NDIMS>> {
+        check_axis::<NDIMS>(axis)?;
+        self.map_axis(axis, self.dims[axis], |lane| {
+            let mut values: Vec<T> = lane.iter().copied().collect();
+            values.sort_by(nan_last);
+            values
+        })
+    }
+}
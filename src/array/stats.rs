@@ -0,0 +1,299 @@
+use crate::array::{ArrResult, ArrType, Array, ArrayBase, Error};
+
+// Statistics promote to `f64` with `as_type` first, so integer arrays get
+// fractional results and `f32` arrays accumulate in double precision.
+
+/// Below this many values, `moments` runs Welford's update directly
+const WELFORD_BLOCK: usize = 16;
+
+/// Count, mean, and sum of squared deviations from the mean
+#[derive(Clone, Copy)]
+struct Moments {
+    count: f64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    /// Combine the moments of two disjoint sets of values (Chan et al.)
+    fn merge(self, other: Self) -> Self {
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        Self {
+            count,
+            mean: self.mean + delta * other.count / count,
+            m2: self.m2 + other.m2 + delta * delta * self.count * other.count / count,
+        }
+    }
+}
+
+/// Welford's update on small blocks, merged pairwise so error grows with
+/// `log(len)` rather than `len`
+fn moments(values: &[f64]) -> Moments {
+    if values.len() > WELFORD_BLOCK {
+        let (left, right) = values.split_at(values.len() / 2);
+        return moments(left).merge(moments(right));
+    }
+
+    let mut acc = Moments {
+        count: 0.0,
+        mean: 0.0,
+        m2: 0.0,
+    };
+    for &x in values {
+        acc.count += 1.0;
+        let delta = x - acc.mean;
+        acc.mean += delta / acc.count;
+        acc.m2 += delta * (x - acc.mean);
+    }
+    acc
+}
+
+fn var_values(values: &[f64], ddof: usize) -> ArrResult<f64> {
+    let len = values.len();
+    if len <= ddof {
+        return Err(Error::VarDdof { len, ddof });
+    }
+
+    Ok(moments(values).m2 / (len - ddof) as f64)
+}
+
+/// How `percentile` picks a value when `q` falls between two sorted values
+/// `lower` and `higher`, matching numpy's interpolation modes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// `lower + (higher - lower) * fraction`
+    Linear,
+    /// `lower`
+    Lower,
+    /// `higher`
+    Higher,
+    /// Whichever is closer, `lower` or `higher`, rounding ties to even
+    Nearest,
+    /// `(lower + higher) / 2`
+    Midpoint,
+}
+
+fn check_q(q: f64) -> ArrResult<()> {
+    if (0.0..=100.0).contains(&q) {
+        Ok(())
+    } else {
+        Err(Error::Percentile { q })
+    }
+}
+
+/// The `q`th percentile, or NaN if any value is NaN
+fn percentile_values(values: &[f64], q: f64, interpolation: Interpolation) -> ArrResult<f64> {
+    if values.is_empty() {
+        return Err(Error::ReduceEmpty);
+    }
+    if values.iter().any(|x| x.is_nan()) {
+        return Ok(f64::NAN);
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let rank = q / 100.0 * (sorted.len() - 1) as f64;
+    let (lower, higher) = (sorted[rank.floor() as usize], sorted[rank.ceil() as usize]);
+    let fraction = rank - rank.floor();
+
+    Ok(match interpolation {
+        Interpolation::Linear => lower + (higher - lower) * fraction,
+        Interpolation::Lower => lower,
+        Interpolation::Higher => higher,
+        Interpolation::Nearest => sorted[rank.round_ties_even() as usize],
+        Interpolation::Midpoint => (lower + higher) / 2.0,
+    })
+}
+
+impl<'base, T: ArrType, const NDIMS: usize> Array<'base, T, NDIMS>
+where
+    f64: From<T>,
+{
+    /// Variance along `axis` with `ddof` delta degrees of freedom, removing `axis`
+    pub fn var(&self, axis: usize, ddof: usize) -> ArrResult<ArrayBase<f64, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        let values = self.as_type::<f64>();
+        values
+            .view()
+            .reduce_axis(axis, |lane| var_values(&lane.contiguous(), ddof))
+    }
+
+    /// Variance along `axis` with `ddof` delta degrees of freedom, keeping `axis` with len 1
+    pub fn var_keepdims(&self, axis: usize, ddof: usize) -> ArrResult<ArrayBase<f64, NDIMS>> {
+        let values = self.as_type::<f64>();
+        values
+            .view()
+            .reduce_axis_keepdims(axis, |lane| var_values(&lane.contiguous(), ddof))
+    }
+
+    /// Variance of every value with `ddof` delta degrees of freedom
+    pub fn var_all(&self, ddof: usize) -> ArrResult<f64> {
+        var_values(&self.as_type::<f64>().data, ddof)
+    }
+
+    /// Standard deviation along `axis` with `ddof` delta degrees of freedom, removing `axis`
+    pub fn std(&self, axis: usize, ddof: usize) -> ArrResult<ArrayBase<f64, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        Ok(self.var(axis, ddof)?.view().map(f64::sqrt))
+    }
+
+    /// Standard deviation along `axis` with `ddof` delta degrees of freedom, keeping `axis` with len 1
+    pub fn std_keepdims(&self, axis: usize, ddof: usize) -> ArrResult<ArrayBase<f64, NDIMS>> {
+        Ok(self.var_keepdims(axis, ddof)?.view().map(f64::sqrt))
+    }
+
+    /// Standard deviation of every value with `ddof` delta degrees of freedom
+    pub fn std_all(&self, ddof: usize) -> ArrResult<f64> {
+        self.var_all(ddof).map(f64::sqrt)
+    }
+
+    /// Median along `axis`, removing it
+    pub fn median(&self, axis: usize) -> ArrResult<ArrayBase<f64, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        self.percentile(50.0, axis, Interpolation::Linear)
+    }
+
+    /// Median of every value
+    pub fn median_all(&self) -> ArrResult<f64> {
+        self.percentile_all(50.0, Interpolation::Linear)
+    }
+
+    /// The `q`th percentile along `axis`, removing it. `q` is within `0..=100`.
+    pub fn percentile(
+        &self,
+        q: f64,
+        axis: usize,
+        interpolation: Interpolation,
+    ) -> ArrResult<ArrayBase<f64, { NDIMS - 1 }>>
+    where
+        [(); NDIMS - 2]: ,
+    {
+        check_q(q)?;
+        let values = self.as_type::<f64>();
+        values.view().reduce_axis(axis, |lane| {
+            percentile_values(&lane.contiguous(), q, interpolation)
+        })
+    }
+
+    /// The `q`th percentile of every value. `q` is within `0..=100`.
+    pub fn percentile_all(&self, q: f64, interpolation: Interpolation) -> ArrResult<f64> {
+        check_q(q)?;
+        percentile_values(&self.as_type::<f64>().data, q, interpolation)
+    }
+
+    /// Count every value into `bins` equal bins over `range`, returning the counts
+    /// and the `bins + 1` bin edges. Without a `range`, the bins span the smallest
+    /// to largest value. Every bin is half open except the last, which includes
+    /// its right edge. Values outside `range`, and NaN, are not counted.
+    pub fn histogram(
+        &self,
+        bins: usize,
+        range: Option<(f64, f64)>,
+    ) -> ArrResult<(ArrayBase<usize, 1>, ArrayBase<f64, 1>)> {
+        let values = self.as_type::<f64>().data;
+
+        let (lo, hi) = match range {
+            Some(range) => range,
+            None => values
+                .iter()
+                .filter(|x| !x.is_nan())
+                .fold(None, |range, &x| match range {
+                    None => Some((x, x)),
+                    Some((lo, hi)) => Some((x.min(lo), x.max(hi))),
+                })
+                .unwrap_or((0.0, 1.0)),
+        };
+        // widen a single value into a bin around it
+        let (lo, hi) = if lo == hi {
+            (lo - 0.5, hi + 0.5)
+        } else {
+            (lo, hi)
+        };
+
+        if bins == 0 || !(lo < hi && lo.is_finite() && hi.is_finite()) {
+            return Err(Error::Histogram {
+                bins,
+                range: (lo, hi),
+            });
+        }
+
+        let width = hi - lo;
+        let mut counts = vec![0; bins];
+        for &x in values.iter().filter(|&&x| lo <= x && x <= hi) {
+            let bin = ((x - lo) / width * bins as f64) as usize;
+            counts[bin.min(bins - 1)] += 1;
+        }
+        let edges = (0..=bins)
+            .map(|i| lo + width * i as f64 / bins as f64)
+            .collect();
+
+        Ok((
+            ArrayBase {
+                dims: [bins],
+                data: counts,
+            },
+            ArrayBase {
+                dims: [bins + 1],
+                data: edges,
+            },
+        ))
+    }
+}
+
+impl<'base, T: ArrType> Array<'base, T, 2>
+where
+    f64: From<T>,
+{
+    /// Covariance matrix, normalized by `n - 1` for `n` observations. With
+    /// `rowvar`, each row is a variable and each col an observation, and the
+    /// other way around without it. The result has a row and col per variable.
+    pub fn cov(&self, rowvar: bool) -> ArrResult<ArrayBase<f64, 2>> {
+        let values = self.as_type::<f64>();
+        let x = if rowvar {
+            values.view()
+        } else {
+            values.view().t()
+        };
+
+        let len = x.dims[0];
+        if len < 2 {
+            return Err(Error::VarDdof { len, ddof: 1 });
+        }
+
+        let centered = x.try_sub(&x.mean_keepdims(0)?.view())?;
+        let centered = centered.view();
+        let mut cov = centered.matmul(&centered.t())?;
+        cov /= (len - 1) as f64;
+
+        Ok(cov)
+    }
+
+    /// Pearson correlation coefficients, laid out like `cov`. Variables with
+    /// zero variance correlate as NaN.
+    pub fn corrcoef(&self, rowvar: bool) -> ArrResult<ArrayBase<f64, 2>> {
+        let cov = self.cov(rowvar)?;
+        let vars = cov.dims[0];
+        let std = ArrayBase {
+            dims: [vars],
+            data: (0..vars).map(|i| cov[[i, i]].sqrt()).collect(),
+        };
+        let std_col = ArrayBase {
+            dims: [1, vars],
+            data: std.data.clone(),
+        };
+
+        // divide each col, then each row, by its variable's standard deviation
+        let corr = cov.try_div(&std.view())?.try_div(&std_col.view())?;
+
+        // rounding can push the coefficients just past 1
+        Ok(corr.view().map(|r| r.clamp(-1.0, 1.0)))
+    }
+}
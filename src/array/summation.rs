@@ -0,0 +1,94 @@
+use crate::array::{ArrResult, ArrType, Array, Error};
+
+/// Values added up naively before `Summation::Pairwise` starts pairing sums
+const PAIRWISE_BLOCK: usize = 8;
+
+/// How to add up a sequence of values. The strategies only differ for floats,
+/// where `Naive` error grows with the number of values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Summation {
+    /// Left to right into one accumulator. Fastest, error grows like `len`.
+    Naive,
+    /// Add blocks, then pairs of block sums, then pairs of those, and so on.
+    /// Error grows like `log(len)` for little extra work.
+    Pairwise,
+    /// Carry the rounding error of every addition into the next one.
+    /// Error stays around one rounding, for four times the work.
+    Kahan,
+}
+
+impl Summation {
+    /// Add up `values`
+    pub fn sum<T: ArrType>(self, values: impl IntoIterator<Item = T>) -> T {
+        let values = values.into_iter();
+        match self {
+            Summation::Naive => values.fold(T::ZERO, |acc, x| acc + x),
+            Summation::Pairwise => pairwise(values),
+            Summation::Kahan => kahan(values),
+        }
+    }
+
+    /// Add up the products of `a` and `b`, pair by pair
+    pub fn dot<T: ArrType>(
+        self,
+        a: impl IntoIterator<Item = T>,
+        b: impl IntoIterator<Item = T>,
+    ) -> T {
+        self.sum(a.into_iter().zip(b).map(|(a, b)| a * b))
+    }
+}
+
+fn pairwise<T: ArrType>(mut values: impl Iterator<Item = T>) -> T {
+    // block sums that are yet to be paired, each with the number of blocks it
+    // covers, which halves towards the top like the digits of a binary counter
+    let mut pending: Vec<(usize, T)> = Vec::new();
+    loop {
+        let mut block = (&mut values).take(PAIRWISE_BLOCK).peekable();
+        if block.peek().is_none() {
+            break;
+        }
+        let mut sum = (1, block.fold(T::ZERO, |acc, x| acc + x));
+
+        while let Some(&(blocks, top)) = pending.last() {
+            if blocks != sum.0 {
+                break;
+            }
+            pending.pop();
+            sum = (blocks * 2, top + sum.1);
+        }
+        pending.push(sum);
+    }
+
+    // smallest sums first
+    pending
+        .iter()
+        .rev()
+        .fold(T::ZERO, |acc, &(_, sum)| acc + sum)
+}
+
+fn kahan<T: ArrType>(values: impl Iterator<Item = T>) -> T {
+    let (sum, _) = values.fold((T::ZERO, T::ZERO), |(sum, compensation), x| {
+        let y = x - compensation;
+        let t = sum + y;
+        // what got rounded off `y` when it was added, negated
+        (t, (t - sum) - y)
+    });
+    sum
+}
+
+impl<'base, T: ArrType> Array<'base, T, 1> {
+    /// Dot product, added up with `ArrType::SUMMATION`
+    pub fn dot(&self, other: &Array<T, 1>) -> ArrResult<T> {
+        self.dot_with(other, T::SUMMATION)
+    }
+
+    /// Dot product, added up with `summation`
+    pub fn dot_with(&self, other: &Array<T, 1>, summation: Summation) -> ArrResult<T> {
+        let (cols_a, rows_b) = (self.dims[0], other.dims[0]);
+        if cols_a != rows_b {
+            return Err(Error::MatMul { cols_a, rows_b });
+        }
+
+        Ok(summation.dot(self.iter().copied(), other.iter().copied()))
+    }
+}
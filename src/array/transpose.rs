@@ -0,0 +1,58 @@
+use crate::array::{ArrResult, Array, ArrayBase, Element, Error};
+
+/// Width of the square tiles copied at a time by `ArrayBase::transposed`,
+/// small enough that a source and destination tile both stay in cache
+const BLOCK: usize = 16;
+
+impl<'base, T: Element, const NDIMS: usize> Array<'base, T, NDIMS> {
+    /// Reorder the dims without copying, so that dim `i` of the result is dim `axes[i]`
+    pub fn permute_axes(&self, axes: [usize; NDIMS]) -> ArrResult<Self> {
+        let mut seen = [false; NDIMS];
+        for &axis in axes.iter() {
+            if axis >= NDIMS || seen[axis] {
+                return Err(Error::PermuteAxes {
+                    axes: axes.to_vec(),
+                });
+            }
+            seen[axis] = true;
+        }
+
+        let mut permuted = *self;
+        for (i, &axis) in axes.iter().enumerate() {
+            permuted.dims[i] = self.dims[axis];
+            permuted.strides[i] = self.strides[axis];
+        }
+
+        Ok(permuted)
+    }
+}
+
+impl<'base, T: Element> Array<'base, T, 2> {
+    /// Transpose without copying
+    pub fn t(&self) -> Self {
+        self.permute_axes([1, 0]).unwrap()
+    }
+}
+
+impl<T: Element> ArrayBase<T, 2> {
+    /// Generate a transposed copy, working through the matrix tile by tile
+    pub fn transposed(&self) -> Self {
+        let (cols, rows) = (self.dims[0], self.dims[1]);
+        let mut data = self.data.clone();
+
+        for row_block in (0..rows).step_by(BLOCK) {
+            for col_block in (0..cols).step_by(BLOCK) {
+                for row in row_block..rows.min(row_block + BLOCK) {
+                    for col in col_block..cols.min(col_block + BLOCK) {
+                        data[col * rows + row] = self.data[row * cols + col];
+                    }
+                }
+            }
+        }
+
+        Self {
+            dims: [rows, cols],
+            data,
+        }
+    }
+}
@@ -4,7 +4,8 @@
 
 #[macro_use]
 pub mod array;
-pub mod tests;
+#[cfg(test)]
+mod tests;
 
 /*
 https://stackoverflow.com/a/57259339/12401179
@@ -14,7 +15,4 @@ very risky tho, only do it once everything works
 
 https://doc.rust-lang.org/edition-guide/rust-2018/error-handling-and-panics/aborting-on-panic.html
 smaller binaries by not unwinding panics
-
-https://crates.io/crates/transpose
-todo: steal algorithm
 */
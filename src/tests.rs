@@ -1,5 +1,5 @@
 mod array_tests {
-    use crate::array::ArrResult;
+    use crate::array::{ArrResult, Error};
 
     #[test]
     fn eq1() -> ArrResult<()> {
@@ -156,6 +156,67 @@ mod array_tests {
 
         Ok(assert_eq!(expected, actual))
     }
+
+    #[test]
+    fn mat_mul_vec_nonsquare() -> ArrResult<()> {
+        arrs!(let mat1 = Array([3,2], vec![1,2,3,4,5,6]));
+        arrs!(let vec1 = [1,0,1]);
+
+        arrs!(let expected = [4,10]);
+        arrs!(let actual = matmul(&mat1, &vec1));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn mat_mul_mat() -> ArrResult<()> {
+        arrs!(let mat1 = Array([3,2], vec![1,2,3,4,5,6]));
+        arrs!(let mat2 = Array([2,3], vec![1,0,0,1,1,1]));
+
+        arrs!(let expected = Array([2,2], vec![4,5,10,11]));
+        arrs!(let actual = matmul(&mat1, &mat2));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn vec_mul_mat() -> ArrResult<()> {
+        arrs!(let vec1 = [1,2,3]);
+        arrs!(let mat1 = Array([2,3], vec![1,0,0,1,1,1]));
+
+        arrs!(let expected = [4,5]);
+        arrs!(let actual = matmul(&vec1, &mat1));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn mat_mul_mat_err() -> ArrResult<()> {
+        arrs!(let mat1 = Array([3,2], vec![1,2,3,4,5,6]));
+        arrs!(let mat2 = Array([3,2], vec![1,2,3,4,5,6]));
+
+        let expected = Error::MatMul {
+            cols_a: 3,
+            rows_b: 2,
+        };
+        let actual = mat1.matmul(&mat2).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn mat_mul_vec_err() -> ArrResult<()> {
+        arrs!(let mat1 = Array([3,2], vec![1,2,3,4,5,6]));
+        arrs!(let vec1 = [1,2]);
+
+        let expected = Error::MatMul {
+            cols_a: 3,
+            rows_b: 2,
+        };
+        let actual = mat1.matmul(&vec1).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
 }
 
 mod derank_slice_tests {
@@ -253,6 +314,1808 @@ mod derank_slice_tests {
     }
 }
 
+mod array_mut_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_derank_mut_fill() -> ArrResult<()> {
+        let mut base = ArrayBase::new([2, 2], vec![0, 1, 2, 3])?;
+        arrs!(let view = ViewMut(base));
+        view.derank_mut(1)?.fill(9);
+
+        arrs!(let expected = Array([2, 2], vec![0, 1, 9, 9]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_slice_mut_assign() -> ArrResult<()> {
+        let mut base = ArrayBase::new([2, 3], vec![0, 1, 2, 3, 4, 5])?;
+        arrs!(let src = Array([2, 2], vec![7, 7, 8, 8]));
+        arrs!(let view = ViewMut(base));
+        view.slice_mut(1, 3)?.assign(&src)?;
+
+        arrs!(let expected = Array([2, 3], vec![0, 1, 7, 7, 8, 8]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_assign_err_mismatched_dims() -> ArrResult<()> {
+        let mut base = ArrayBase::new([2, 2], vec![0, 1, 2, 3])?;
+        arrs!(let src = Array([1, 2], vec![1, 2]));
+        arrs!(let view = ViewMut(base));
+
+        let expected = Error::Broadcast {
+            dims1: vec![2, 1],
+            dims2: vec![1, 2],
+        };
+        let actual = view.slice_mut(0, 1)?.assign(&src).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod strided_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_select_inner_axis() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        arrs!(let expected = [1, 4]);
+        let actual = arr.select(0, 1)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_slice_axis_step() -> ArrResult<()> {
+        arrs!(let arr = Array([5, 2], vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+
+        arrs!(let expected = Array([3, 2], vec![0, 2, 4, 5, 7, 9]));
+        let actual = arr.slice_axis(0, 0, 5, 2)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_slice_axis_reversed() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        arrs!(let expected = Array([2, 2], vec![2, 1, 5, 4]));
+        let actual = arr.slice_axis(0, 1, 3, -1)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_slice_axis_err_zero_step() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        let expected = Error::SliceZeroStep;
+        let actual = arr.slice_axis(0, 0, 3, 0).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_select_err_axis_out_of_bounds() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        let expected = Error::AxisOutOfBounds { axis: 2, ndims: 2 };
+        let actual = arr.select(2, 0).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_add_strided() -> ArrResult<()> {
+        arrs!(let arr1 = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+        arrs!(let arr2 = [10, 20]);
+        let column = arr1.select(0, 2)?;
+
+        arrs!(let expected = [12, 25]);
+        arrs!(let actual = add(&column, &arr2));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_dot_reversed() -> ArrResult<()> {
+        arrs!(let vec1 = [1, 2, 3]);
+        arrs!(let vec2 = [1, 0, 0]);
+        let reversed = vec1.slice_axis(0, 0, 3, -1)?;
+
+        arrs!(let expected = [3]);
+        arrs!(let actual = matmul(&reversed, &vec2));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_into_base_strided() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+        let base = arr.slice_axis(0, 0, 3, 2)?.into_base();
+
+        arrs!(let expected = Array([2, 2], vec![0, 2, 3, 5]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_select_mut_fill() -> ArrResult<()> {
+        let mut base = ArrayBase::new([3, 2], vec![0, 1, 2, 3, 4, 5])?;
+        arrs!(let view = ViewMut(base));
+        view.select_mut(0, 1)?.fill(9);
+
+        arrs!(let expected = Array([3, 2], vec![0, 9, 2, 3, 9, 5]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod transpose_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_t() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        arrs!(let expected = Array([2, 3], vec![0, 3, 1, 4, 2, 5]));
+        let actual = arr.t();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_permute_axes() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 1, 3], vec![0, 1, 2, 3, 4, 5]));
+
+        arrs!(let expected = Array([3, 2, 1], vec![0, 2, 4, 1, 3, 5]));
+        let actual = arr.permute_axes([2, 0, 1])?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_permute_axes_err_repeated_axis() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 1, 3], vec![0, 1, 2, 3, 4, 5]));
+
+        let expected = Error::PermuteAxes {
+            axes: vec![0, 0, 1],
+        };
+        let actual = arr.permute_axes([0, 0, 1]).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_transposed_matches_t() -> ArrResult<()> {
+        let base = ArrayBase::new([37, 20], (0..740).collect())?;
+        arrs!(let arr = View(base));
+
+        let transposed = base.transposed();
+        arrs!(let actual = View(transposed));
+
+        Ok(assert_eq!(arr.t(), actual))
+    }
+}
+
+mod gemm_tests {
+    use crate::array::gemm;
+    use crate::array::{ArrResult, ArrayBase};
+
+    // small integers keep every float product and sum exact
+    fn matrix<T: From<u8>>(len: usize, seed: usize) -> Vec<T> {
+        (0..len).map(|i| T::from(((i * seed) % 7) as u8)).collect()
+    }
+
+    #[test]
+    fn test_packed_matches_naive() {
+        let (m, k, n) = (70, 300, 13);
+        let (a, b) = (matrix::<f32>(m * k, 3), matrix::<f32>(k * n, 5));
+
+        let mut expected = vec![0.0; m * n];
+        gemm::naive(m, k, n, &a, &b, &mut expected);
+
+        for &threads in [1, 3].iter() {
+            let mut actual = vec![0.0; m * n];
+            gemm::packed(m, k, n, &a, &b, &mut actual, threads);
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_zero_inner_dim() -> ArrResult<()> {
+        let (m, k, n) = (200, 0, 3);
+        let mut c = vec![1.0f32; m * n];
+        gemm::packed(m, k, n, &[], &[], &mut c, 4);
+        assert_eq!(vec![0.0; m * n], c);
+
+        let mut c = vec![1; m * n];
+        gemm::blocked(m, k, n, &[], &[], &mut c, 4);
+        assert_eq!(vec![0; m * n], c);
+
+        let a = ArrayBase::<f32, 2>::new([0, 200], vec![])?;
+        let b = ArrayBase::<f32, 2>::new([3, 0], vec![])?;
+
+        let expected = ArrayBase::zeros([3, 200])?;
+        let actual = a.view().matmul(&b.view())?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_blocked_matches_naive() {
+        let (m, k, n) = (130, 9, 17);
+        let (a, b) = (matrix::<i32>(m * k, 3), matrix::<i32>(k * n, 5));
+
+        let mut expected = vec![0; m * n];
+        gemm::naive(m, k, n, &a, &b, &mut expected);
+
+        let mut actual = vec![0; m * n];
+        gemm::blocked(m, k, n, &a, &b, &mut actual, 2);
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_matmul_with_threads() -> ArrResult<()> {
+        let (m, k, n) = (150, 40, 20);
+        let mat1 = ArrayBase::new([k, m], matrix::<f64>(m * k, 3))?;
+        let mat2 = ArrayBase::new([n, k], matrix::<f64>(k * n, 5))?;
+        arrs!(let mat1 = View(mat1));
+        arrs!(let mat2 = View(mat2));
+
+        let expected = mat1.matmul_with_threads(&mat2, 1)?;
+        let actual = mat1.matmul_with_threads(&mat2, 4)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod batch_matmul_tests {
+    use crate::array::{ArrResult, Error};
+
+    #[test]
+    fn test_batch_mul_mat() -> ArrResult<()> {
+        arrs!(let batch = Array([3, 2, 2], vec![1, 2, 3, 4, 5, 6, 0, 1, 0, 1, 0, 1]));
+        arrs!(let mat = Array([2, 3], vec![1, 0, 0, 1, 1, 1]));
+
+        arrs!(let expected = Array([2, 2, 2], vec![4, 5, 10, 11, 0, 1, 2, 1]));
+        let product = batch.batch_matmul(&mat)?;
+        arrs!(let actual = View(product));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_batch_matches_matmul() -> ArrResult<()> {
+        arrs!(let batch1 = Array([2, 2, 3], (0..12).collect()));
+        arrs!(let batch2 = Array([2, 2, 1], vec![1, 2, 3, 4]));
+        let product = batch1.batch_matmul(&batch2)?;
+        arrs!(let product = View(product));
+
+        let mat2 = batch2.derank(0)?;
+        for index in 0..3 {
+            let expected = batch1.derank(index)?.matmul(&mat2)?;
+            arrs!(let expected = View(expected));
+            let actual = product.derank(index)?;
+            assert_eq!(expected, actual);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_empty_matrices() -> ArrResult<()> {
+        arrs!(let batch1 = Array([2, 0, 2], vec![]));
+        arrs!(let batch2 = Array([3, 2, 2], (0..12).collect()));
+
+        arrs!(let expected = Array([3, 0, 2], vec![]));
+        let product = batch1.batch_matmul(&batch2)?;
+        arrs!(let actual = View(product));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_batch_err_broadcast() -> ArrResult<()> {
+        arrs!(let batch1 = Array([2, 2, 3], (0..12).collect()));
+        arrs!(let batch2 = Array([2, 2, 2], (0..8).collect()));
+
+        let expected = Error::Broadcast {
+            dims1: vec![2, 2, 3],
+            dims2: vec![2, 2, 2],
+        };
+        let actual = batch1.batch_matmul(&batch2).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_batch_err_matmul() -> ArrResult<()> {
+        arrs!(let batch = Array([3, 2, 2], (0..12).collect()));
+        arrs!(let mat = Array([3, 2], (0..6).collect()));
+
+        let expected = Error::MatMul {
+            cols_a: 3,
+            rows_b: 2,
+        };
+        let actual = batch.batch_matmul(&mat).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod reshape_tests {
+    use crate::array::{ArrResult, Error};
+
+    #[test]
+    fn test_reshape() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 2, 3], (0..12).collect()));
+
+        arrs!(let expected = Array([4, 3], (0..12).collect()));
+        let actual = arr.reshape([4, 3])?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_reshape_err_misaligned() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 3], (0..6).collect()));
+
+        let expected = Error::ShapeDataMisalignment { volume: 4, len: 6 };
+        let actual = arr.reshape([2, 2]).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_reshape_err_not_contiguous() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 3], (0..6).collect()));
+
+        let expected = Error::NotContiguous {
+            dims: vec![3, 2],
+            strides: vec![2, 1],
+        };
+        let actual = arr.t().reshape([6]).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_flatten_slice() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 3], (0..6).collect()));
+
+        arrs!(let expected = [2, 3, 4, 5]);
+        let actual = arr.slice(1, 3)?.flatten()?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_expand_then_squeeze() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 3], (0..6).collect()));
+
+        let expanded = arr.expand_dims(1)?;
+        arrs!(let expected = Array([2, 1, 3], (0..6).collect()));
+        assert_eq!(expected, expanded);
+
+        Ok(assert_eq!(arr, expanded.squeeze_axis(1)?))
+    }
+
+    #[test]
+    fn test_squeeze_err_len_not_one() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 3], (0..6).collect()));
+
+        let expected = Error::SqueezeLenNotOne { axis: 0, len: 2 };
+        let actual = arr.squeeze_axis(0).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod join_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_concatenate_outer() -> ArrResult<()> {
+        arrs!(let arr1 = Array([2, 1], vec![0, 1]));
+        arrs!(let arr2 = Array([2, 2], vec![2, 3, 4, 5]));
+
+        arrs!(let expected = Array([2, 3], (0..6).collect()));
+        let joined = ArrayBase::concatenate(&[arr1, arr2], 1)?;
+        arrs!(let actual = View(joined));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_concatenate_inner() -> ArrResult<()> {
+        arrs!(let arr1 = Array([1, 2], vec![0, 3]));
+        arrs!(let arr2 = Array([2, 2], vec![1, 2, 4, 5]));
+
+        arrs!(let expected = Array([3, 2], (0..6).collect()));
+        let joined = ArrayBase::concatenate(&[arr1, arr2], 0)?;
+        arrs!(let actual = View(joined));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_concatenate_err_mismatched_dims() -> ArrResult<()> {
+        arrs!(let arr1 = Array([1, 2], vec![0, 3]));
+        arrs!(let arr2 = Array([2, 2], vec![1, 2, 4, 5]));
+
+        let expected = Error::JoinMismatchedDims {
+            dims1: vec![1, 2],
+            dims2: vec![2, 2],
+            axis: 1,
+        };
+        let actual = ArrayBase::concatenate(&[arr1, arr2], 1).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_stack() -> ArrResult<()> {
+        arrs!(let arr1 = [0, 1]);
+        arrs!(let arr2 = [2, 3]);
+
+        arrs!(let expected = Array([2, 2], vec![0, 2, 1, 3]));
+        let stacked = ArrayBase::stack(&[arr1, arr2], 0)?;
+        arrs!(let actual = View(stacked));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_split() -> ArrResult<()> {
+        arrs!(let arr = Array([2, 3], (0..6).collect()));
+        let pieces = arr.split(3, 1)?;
+
+        arrs!(let expected = [2, 3]);
+
+        assert_eq!(3, pieces.len());
+        Ok(assert_eq!(expected, pieces[1].flatten()?))
+    }
+
+    #[test]
+    fn test_array_split_uneven() -> ArrResult<()> {
+        arrs!(let arr = [0, 1, 2, 3, 4]);
+        let pieces = arr.array_split(3, 0)?;
+
+        arrs!(let expected1 = [0, 1]);
+        arrs!(let expected2 = [2, 3]);
+        arrs!(let expected3 = [4]);
+
+        Ok(assert_eq!(vec![expected1, expected2, expected3], pieces))
+    }
+
+    #[test]
+    fn test_split_err_uneven() -> ArrResult<()> {
+        arrs!(let arr = [0, 1, 2, 3, 4]);
+
+        let expected = Error::Split {
+            len: 5,
+            sections: 3,
+        };
+        let actual = arr.split(3, 0).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod construct_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_zeros() -> ArrResult<()> {
+        let base = ArrayBase::<i32, 2>::zeros([2, 3])?;
+
+        arrs!(let expected = Array([2, 3], vec![0; 6]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_full() -> ArrResult<()> {
+        let base = ArrayBase::full([3], 1.5)?;
+
+        arrs!(let expected = [1.5, 1.5, 1.5]);
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_zeros_err_zero_len_dim() -> ArrResult<()> {
+        let expected = Error::ShapeZeroLenDim { dims: vec![2, 0] };
+        let actual = ArrayBase::<f32, 2>::ones([2, 0]).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_from_fn() -> ArrResult<()> {
+        let base = ArrayBase::from_fn([3, 2], |[col, row]| (10 * row + col) as i32)?;
+
+        arrs!(let expected = Array([3, 2], vec![0, 1, 2, 10, 11, 12]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_arange() -> ArrResult<()> {
+        let base = ArrayBase::arange(5, -3, -2)?;
+
+        arrs!(let expected = [5, 3, 1, -1]);
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_arange_err_empty() -> ArrResult<()> {
+        let expected = Error::ShapeZeroLenDim { dims: vec![0] };
+        let actual = ArrayBase::arange(0.0, -1.0, 0.5).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_arange_small_int() -> ArrResult<()> {
+        let unsigned = ArrayBase::<u8, 1>::arange(0, 255, 2)?;
+        let signed = ArrayBase::<i8, 1>::arange(-128, 127, 1)?;
+
+        assert_eq!(&[128], unsigned.view().dims());
+        assert_eq!(Some(&254), unsigned.view().as_slice().unwrap().last());
+        assert_eq!(&[255], signed.view().dims());
+        Ok(assert_eq!(
+            Some(&126),
+            signed.view().as_slice().unwrap().last()
+        ))
+    }
+
+    #[test]
+    fn test_arange_float_len() -> ArrResult<()> {
+        let base = ArrayBase::<f64, 1>::arange(0.0, 1.0, 0.1)?;
+        let last = base.view().as_slice().unwrap()[9];
+
+        assert_eq!(&[10], base.view().dims());
+        Ok(assert!((last - 0.9).abs() < 1e-12))
+    }
+
+    #[test]
+    fn test_linspace() -> ArrResult<()> {
+        let base = ArrayBase::linspace(0.0, 1.0, 5)?;
+
+        arrs!(let expected = [0.0, 0.25, 0.5, 0.75, 1.0]);
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_linspace_descending_unsigned() -> ArrResult<()> {
+        let base = ArrayBase::<u8, 1>::linspace(10, 0, 3)?;
+
+        arrs!(let expected = [10u8, 5, 0]);
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_linspace_small_int() -> ArrResult<()> {
+        let base = ArrayBase::<u8, 1>::linspace(0, 200, 300)?;
+        let values = base.view().as_slice().unwrap();
+
+        assert_eq!(&[300], base.view().dims());
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        Ok(assert_eq!(
+            (0, 100, 200),
+            (values[0], values[150], values[299])
+        ))
+    }
+
+    #[test]
+    fn test_eye() -> ArrResult<()> {
+        let base = ArrayBase::eye([3, 2])?;
+
+        arrs!(let expected = Array([3, 2], vec![1, 0, 0, 0, 1, 0]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_identity_matmul() -> ArrResult<()> {
+        let identity = ArrayBase::identity(3)?;
+        arrs!(let identity = View(identity));
+        arrs!(let arr = Array([3, 2], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+
+        let product = arr.matmul(&identity)?;
+        arrs!(let actual = View(product));
+
+        Ok(assert_eq!(arr, actual))
+    }
+}
+
+mod index_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_index_view() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        assert_eq!(5, arr[[2, 1]]);
+        Ok(assert_eq!(3, arr.t()[[1, 0]]))
+    }
+
+    #[test]
+    fn test_index_mut_base() -> ArrResult<()> {
+        let mut base = ArrayBase::<i32, 2>::zeros([3, 2])?;
+        base[[1, 1]] = 7;
+
+        arrs!(let expected = Array([3, 2], vec![0, 0, 0, 0, 7, 0]));
+        arrs!(let actual = View(base));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_get_mut_view() -> ArrResult<()> {
+        let mut base = ArrayBase::<i32, 2>::zeros([3, 2])?;
+        arrs!(let view = ViewMut(base));
+        *view.slice_axis_mut(0, 0, 3, -1)?.get_mut([0, 0])? = 7;
+
+        Ok(assert_eq!(7, base[[2, 0]]))
+    }
+
+    #[test]
+    fn test_get_err_out_of_bounds() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        let expected = Error::IndexOutOfBounds {
+            axis: 1,
+            len: 2,
+            index: 2,
+        };
+        let actual = arr.get([0, 2]).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is out of bounds for axis 0 with len 3")]
+    fn test_index_panics_out_of_bounds() {
+        let base = ArrayBase::<i32, 2>::zeros([3, 2]).unwrap();
+        let _ = base[[3, 0]];
+    }
+}
+
+mod ops_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_affine() -> ArrResult<()> {
+        arrs!(let x = Array([2, 2], vec![1, 2, 3, 4]));
+        arrs!(let w = [10, 100]);
+        let b = ArrayBase::new([1, 2], vec![1, 2])?;
+
+        let actual = &(&x * &w) + &b;
+        let expected = ArrayBase::new([2, 2], vec![11, 201, 32, 402])?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_scalar_ops() -> ArrResult<()> {
+        arrs!(let x = [1.0, 2.0, 4.0]);
+
+        let expected = ArrayBase::new([3], vec![1.0, 0.5, 0.25])?;
+        let actual = 1.0 / &x;
+        assert_eq!(expected, actual);
+
+        let expected = ArrayBase::new([3], vec![-1.0, 0.0, 2.0])?;
+        Ok(assert_eq!(expected, &x - 2.0))
+    }
+
+    #[test]
+    fn test_try_sub_err_broadcast() -> ArrResult<()> {
+        arrs!(let x = [1, 2, 3]);
+        arrs!(let y = [1, 2]);
+
+        let expected = Error::Broadcast {
+            dims1: vec![3],
+            dims2: vec![2],
+        };
+        let actual = x.try_sub(&y).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    #[should_panic(expected = "operands could not be broadcast together with shapes [3], [2]")]
+    fn test_add_panics_broadcast() {
+        let x = ArrayBase::new([3], vec![1, 2, 3]).unwrap();
+        let y = ArrayBase::new([2], vec![1, 2]).unwrap();
+        let _ = &x + &y;
+    }
+}
+
+mod assign_ops_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_sub_assign_broadcast() -> ArrResult<()> {
+        let mut weights = ArrayBase::new([2, 2], vec![1.0, 2.0, 3.0, 4.0])?;
+        let grads = ArrayBase::new([2], vec![0.5, 1.0])?;
+        weights -= &grads;
+
+        let expected = ArrayBase::new([2, 2], vec![0.5, 1.0, 2.5, 3.0])?;
+
+        Ok(assert_eq!(expected, weights))
+    }
+
+    #[test]
+    fn test_mul_assign_scalar_view() -> ArrResult<()> {
+        let mut base = ArrayBase::new([2, 2], vec![1, 2, 3, 4])?;
+        let mut row = base.view_mut();
+        let mut row = row.derank_mut(1)?;
+        row *= 10;
+
+        let expected = ArrayBase::new([2, 2], vec![1, 2, 30, 40])?;
+
+        Ok(assert_eq!(expected, base))
+    }
+
+    #[test]
+    fn test_add_assign_strided_view() -> ArrResult<()> {
+        let mut base = ArrayBase::new([3, 2], vec![0, 1, 2, 3, 4, 5])?;
+        let other = ArrayBase::new([1, 2], vec![10, 20])?;
+        let mut view = base.view_mut();
+        let mut column = view.slice_axis_mut(0, 0, 3, 2)?;
+        column += &other;
+
+        let expected = ArrayBase::new([3, 2], vec![10, 1, 12, 23, 4, 25])?;
+
+        Ok(assert_eq!(expected, base))
+    }
+
+    #[test]
+    fn test_try_add_assign_err_grow() -> ArrResult<()> {
+        let mut base = ArrayBase::new([1, 2], vec![1, 2])?;
+        arrs!(let other = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        let expected = Error::Broadcast {
+            dims1: vec![1, 2],
+            dims2: vec![3, 2],
+        };
+        let actual = base.try_add_assign(&other).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod combine_into_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_combine_into_reused_buffer() -> ArrResult<()> {
+        arrs!(let arr1 = Array([2, 2], vec![1, 2, 3, 4]));
+        arrs!(let arr2 = [10, 20]);
+        let mut out = ArrayBase::zeros([2, 2])?;
+
+        arr1.broadcast_combine_into(&arr2, &mut out.view_mut(), |a, b| a + b)?;
+        assert_eq!(ArrayBase::new([2, 2], vec![11, 22, 13, 24])?, out);
+
+        arr1.broadcast_combine_into(&arr2, &mut out.view_mut(), |a, b| a * b)?;
+        Ok(assert_eq!(
+            ArrayBase::new([2, 2], vec![10, 40, 30, 80])?,
+            out
+        ))
+    }
+
+    #[test]
+    fn test_combine_into_strided_out() -> ArrResult<()> {
+        arrs!(let arr1 = [1, 2, 3]);
+        arrs!(let arr2 = [1]);
+        let mut out = ArrayBase::zeros([3, 2])?;
+        let mut view = out.view_mut();
+
+        arr1.broadcast_combine_into(&arr2, &mut view.select_mut(1, 1)?, |a, b| a - b)?;
+
+        Ok(assert_eq!(
+            ArrayBase::new([3, 2], vec![0, 0, 0, 0, 1, 2])?,
+            out
+        ))
+    }
+
+    #[test]
+    fn test_combine_into_err_out_dims() -> ArrResult<()> {
+        arrs!(let arr1 = [1, 2, 3]);
+        arrs!(let arr2 = [1]);
+        let mut out = ArrayBase::zeros([2])?;
+
+        let expected = Error::BroadcastInto {
+            dims: vec![3],
+            out: vec![2],
+        };
+        let actual = arr1
+            .broadcast_combine_into(&arr2, &mut out.view_mut(), |a, b| a + b)
+            .unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod broadcast_to_tests {
+    use crate::array::{ArrResult, Error};
+
+    #[test]
+    fn test_broadcast_to() -> ArrResult<()> {
+        arrs!(let arr = [1, 2]);
+
+        arrs!(let expected = Array([2, 3], vec![1, 2, 1, 2, 1, 2]));
+        let actual = arr.broadcast_to([2, 3])?;
+
+        assert_eq!(&[1, 0], actual.strides());
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_broadcast_to_stretch_inner() -> ArrResult<()> {
+        arrs!(let arr = Array([1, 2], vec![1, 2]));
+
+        arrs!(let expected = Array([3, 2], vec![1, 1, 1, 2, 2, 2]));
+        let actual = arr.broadcast_to([3, 2])?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_broadcast_to_matches_combine() -> ArrResult<()> {
+        arrs!(let arr1 = Array([1, 2], vec![1, 2]));
+        arrs!(let arr2 = [10, 20, 30]);
+
+        let dims = arr1.broadcast_dims(&arr2)?;
+        assert_eq!([3, 2], dims);
+
+        arrs!(let expected = add(&arr1, &arr2));
+        let actual = arr1
+            .broadcast_to(dims)?
+            .try_add(&arr2.broadcast_to(dims)?)?;
+        arrs!(let actual = View(actual));
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_broadcast_to_err_shrink() -> ArrResult<()> {
+        arrs!(let arr = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        let expected = Error::Broadcast {
+            dims1: vec![3, 2],
+            dims2: vec![3],
+        };
+        let actual = arr.broadcast_to([3]).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod combine_many_tests {
+    use crate::array::{ArrResult, Array, ArrayBase, Error};
+
+    #[test]
+    fn test_where() -> ArrResult<()> {
+        arrs!(let cond = Array([2, 2], vec![1, 0, 0, 1]));
+        arrs!(let x = [1, 2]);
+        arrs!(let y = [-1]);
+
+        let expected = ArrayBase::new([2, 2], vec![1, -1, -1, 2])?;
+        let actual = cond.where_(&x, &y)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_clip() -> ArrResult<()> {
+        arrs!(let x = Array([3, 2], vec![-5.0, 0.5, 5.0, -5.0, 0.5, 5.0]));
+        arrs!(let lo = Array([1, 2], vec![0.0, -1.0]));
+        arrs!(let hi = [1.0]);
+
+        let expected = ArrayBase::new([3, 2], vec![0.0, 0.5, 1.0, -1.0, 0.5, 1.0])?;
+        let actual = x.clip(&lo, &hi)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_fma() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![1, 2, 3, 4]));
+        arrs!(let b = [10]);
+        arrs!(let c = [1, 2]);
+
+        let expected = ArrayBase::new([2, 2], vec![11, 22, 31, 42])?;
+        let actual = a.fma(&b, &c)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_combine_many() -> ArrResult<()> {
+        arrs!(let a = [1, 2, 3]);
+        arrs!(let b = [10]);
+        arrs!(let c = [100, 200, 300]);
+        arrs!(let d = [1000]);
+
+        let expected = ArrayBase::new([3], vec![1111, 1212, 1313])?;
+        let actual = Array::broadcast_combine_many(&[a, b, c, d], |xs| xs.iter().sum())?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_fma_len_0_against_len_1() -> ArrResult<()> {
+        arrs!(let a = Array([0, 2], vec![]));
+        arrs!(let b = [10]);
+        arrs!(let c = Array([1, 2], vec![1, 2]));
+
+        let expected = ArrayBase::new([0, 2], vec![])?;
+        let actual = a.fma(&b, &c)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_combine_many_err_empty() -> ArrResult<()> {
+        let expected = Error::CombineEmpty;
+        let actual =
+            Array::<i32, 1>::broadcast_combine_many(&[], |xs| xs.iter().sum()).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_fma_err_lists_every_operand() -> ArrResult<()> {
+        arrs!(let a = [1, 2, 3]);
+        arrs!(let b = [1]);
+        arrs!(let c = Array([2, 2], vec![1, 2, 3, 4]));
+
+        let expected = Error::BroadcastMany {
+            dims: vec![vec![3], vec![1], vec![2, 2]],
+        };
+        let actual = a.fma(&b, &c).unwrap_err();
+
+        assert_eq!(
+            "operands could not be broadcast together with shapes [3], [1], [2, 2]",
+            actual.to_string()
+        );
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod mixed_type_tests {
+    use crate::array::{ArrResult, ArrayBase};
+
+    #[test]
+    fn test_combine_u8_f32() -> ArrResult<()> {
+        arrs!(let pixels = Array([2, 2], vec![0u8, 51, 102, 255]));
+        arrs!(let scale = [1.0f32, 0.5]);
+
+        let expected = ArrayBase::new([2, 2], vec![0.0f32, 25.5, 102.0, 127.5])?;
+        let actual = pixels.broadcast_combine(&scale, |p, s| f32::from(p) * s)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_combine_comparison_mask() -> ArrResult<()> {
+        arrs!(let a = [0.5, 1.5, 2.5]);
+        arrs!(let b = [1.0]);
+
+        let expected = ArrayBase::new([3], vec![0u8, 1, 1])?;
+        let actual = a.broadcast_combine(&b, |x, y| (x > y) as u8)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_combine_into_mixed() -> ArrResult<()> {
+        arrs!(let a = [1i16, 2, 3]);
+        arrs!(let b = [2i8]);
+        let mut out = ArrayBase::<f64, 1>::zeros([3])?;
+
+        a.broadcast_combine_into(&b, &mut out.view_mut(), |x, y| f64::from(x) / f64::from(y))?;
+
+        Ok(assert_eq!(ArrayBase::new([3], vec![0.5, 1.0, 1.5])?, out))
+    }
+
+    #[test]
+    fn test_where_mixed() -> ArrResult<()> {
+        arrs!(let mask = [1u8, 0, 1]);
+        arrs!(let x = [1.5, 2.5, 3.5]);
+        arrs!(let y = [0.0]);
+
+        let expected = ArrayBase::new([3], vec![1.5, 0.0, 3.5])?;
+        let actual = mask.where_(&x, &y)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod compare_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_gt_broadcast() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+        arrs!(let b = Array([1, 2], vec![1, 4]));
+
+        let expected = ArrayBase::new([3, 2], vec![false, false, true, false, false, true])?;
+        let actual = a.gt(&b)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_eq_elem_and_le() -> ArrResult<()> {
+        arrs!(let a = [1.0, 2.0, 3.0]);
+        arrs!(let b = [2.0]);
+
+        assert_eq!(
+            ArrayBase::new([3], vec![false, true, false])?,
+            a.eq_elem(&b)?
+        );
+        Ok(assert_eq!(
+            ArrayBase::new([3], vec![true, true, false])?,
+            a.le(&b)?
+        ))
+    }
+
+    #[test]
+    fn test_any_all() -> ArrResult<()> {
+        arrs!(let a = [1, 2, 3]);
+        arrs!(let b = [0]);
+        let mask = a.gt(&b)?;
+
+        assert!(mask.all());
+        Ok(assert!(!a.lt(&b)?.any()))
+    }
+
+    #[test]
+    fn test_where_bool_mask() -> ArrResult<()> {
+        arrs!(let x = [-1.0, 2.0, -3.0]);
+        arrs!(let zero = [0.0]);
+        let mask = x.ge(&zero)?;
+        arrs!(let mask = View(mask));
+
+        let expected = ArrayBase::new([3], vec![0.0, 2.0, 0.0])?;
+        let actual = mask.where_(&x, &zero)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_ne_elem_err_broadcast() -> ArrResult<()> {
+        arrs!(let a = [true, false]);
+        arrs!(let b = [true, false, true]);
+
+        let expected = Error::Broadcast {
+            dims1: vec![2],
+            dims2: vec![3],
+        };
+        let actual = a.ne_elem(&b).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod mask_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_masked_select_broadcast() -> ArrResult<()> {
+        // three samples of two pixels each, keeping those labelled 7
+        arrs!(let samples = Array([2, 3], vec![1, 2, 3, 4, 5, 6]));
+        arrs!(let labels = Array([1, 3], vec![7, 1, 7]));
+        arrs!(let seven = [7]);
+        let mask = labels.eq_elem(&seven)?;
+        arrs!(let mask = View(mask));
+
+        let expected = ArrayBase::new([4], vec![1, 2, 5, 6])?;
+        let actual = samples.masked_select(&mask)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_masked_select_err() -> ArrResult<()> {
+        arrs!(let a = [1, 2, 3]);
+        arrs!(let mask = [true, false]);
+
+        let expected = Error::Broadcast {
+            dims1: vec![3],
+            dims2: vec![2],
+        };
+        let actual = a.masked_select(&mask).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_masked_fill() -> ArrResult<()> {
+        let mut a = ArrayBase::new([2, 2], vec![1.0, -2.0, -3.0, 4.0])?;
+        let mask = a.view().lt(&ArrayBase::new([1], vec![0.0])?.view())?;
+        a.masked_fill(&mask.view(), 0.0)?;
+
+        let expected = ArrayBase::new([2, 2], vec![1.0, 0.0, 0.0, 4.0])?;
+
+        Ok(assert_eq!(expected, a))
+    }
+
+    #[test]
+    fn test_nonzero() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![0, 5, 0, 7, 0, 8]));
+
+        let [cols, rows] = a.nonzero();
+
+        assert_eq!(ArrayBase::new([3], vec![1, 0, 2])?, cols);
+        Ok(assert_eq!(ArrayBase::new([3], vec![0, 1, 1])?, rows))
+    }
+
+    #[test]
+    fn test_argwhere() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![false, true, true, false]));
+
+        let expected = ArrayBase::new([2, 2], vec![1, 0, 0, 1])?;
+        let actual = a.argwhere();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_argwhere_none() -> ArrResult<()> {
+        arrs!(let a = [0.0, 0.0]);
+
+        let expected = ArrayBase::new([1, 0], vec![])?;
+        let actual = a.argwhere();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod take_put_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_take_rows() -> ArrResult<()> {
+        arrs!(let a = Array([2, 3], vec![0, 1, 2, 3, 4, 5]));
+        arrs!(let indices = [2, 0, 2]);
+
+        let expected = ArrayBase::new([2, 3], vec![4, 5, 0, 1, 4, 5])?;
+        let actual = a.take(&indices, 1)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_take_strided() -> ArrResult<()> {
+        arrs!(let a = Array([2, 3], vec![0, 1, 2, 3, 4, 5]));
+        let t = a.t();
+        arrs!(let indices = [1]);
+
+        let expected = ArrayBase::new([1, 2], vec![2, 3])?;
+        let actual = t.take(&indices, 0)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_take_err_index() -> ArrResult<()> {
+        arrs!(let a = Array([2, 3], vec![0, 1, 2, 3, 4, 5]));
+        arrs!(let indices = [1, 3]);
+
+        let expected = Error::IndexOutOfBounds {
+            axis: 1,
+            len: 3,
+            index: 3,
+        };
+        let actual = a.take(&indices, 1).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_take_err_axis() -> ArrResult<()> {
+        arrs!(let a = [1, 2]);
+        arrs!(let indices = [0]);
+
+        let expected = Error::AxisOutOfBounds { axis: 1, ndims: 1 };
+        let actual = a.take(&indices, 1).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_put_rows() -> ArrResult<()> {
+        let mut a = ArrayBase::new([2, 3], vec![0; 6])?;
+        arrs!(let indices = [2, 0]);
+        arrs!(let values = Array([2, 2], vec![1, 2, 3, 4]));
+        a.put(&indices, &values, 1)?;
+
+        let expected = ArrayBase::new([2, 3], vec![3, 4, 0, 0, 1, 2])?;
+
+        Ok(assert_eq!(expected, a))
+    }
+
+    #[test]
+    fn test_put_broadcast() -> ArrResult<()> {
+        let mut a = ArrayBase::new([3, 2], vec![0; 6])?;
+        arrs!(let indices = [1]);
+        arrs!(let values = [9]);
+        a.put(&indices, &values, 0)?;
+
+        let expected = ArrayBase::new([3, 2], vec![0, 9, 0, 0, 9, 0])?;
+
+        Ok(assert_eq!(expected, a))
+    }
+
+    #[test]
+    fn test_put_err_broadcast() -> ArrResult<()> {
+        let mut a = ArrayBase::new([3], vec![0; 3])?;
+        arrs!(let indices = [0, 1]);
+        arrs!(let values = [1, 2, 3]);
+
+        let expected = Error::Broadcast {
+            dims1: vec![2],
+            dims2: vec![3],
+        };
+        let actual = a.put(&indices, &values, 0).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod reduce_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_mean_u8_long_lane() -> ArrResult<()> {
+        let base = ArrayBase::<u8, 2>::from_fn([784, 2], |[_, row]| 200 + row as u8)?;
+
+        let mean = base.view().mean(0)?;
+
+        arrs!(let expected = [200, 201]);
+        arrs!(let actual = View(mean));
+
+        assert_eq!(expected, actual);
+        Ok(assert_eq!(200, base.view().mean_all()?))
+    }
+
+    #[test]
+    fn test_sum_axis() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+
+        assert_eq!(ArrayBase::new([2], vec![3, 12])?, a.sum(0)?);
+        Ok(assert_eq!(ArrayBase::new([3], vec![3, 5, 7])?, a.sum(1)?))
+    }
+
+    #[test]
+    fn test_sum_strided() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![0, 1, 2, 3, 4, 5]));
+        let t = a.t();
+
+        Ok(assert_eq!(ArrayBase::new([2], vec![3, 12])?, t.sum(1)?))
+    }
+
+    #[test]
+    fn test_keepdims_broadcast() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![1.0, 3.0, 2.0, 6.0]));
+        let mean = a.mean_keepdims(0)?;
+
+        assert_eq!(ArrayBase::new([1, 2], vec![2.0, 4.0])?, mean);
+
+        let expected = ArrayBase::new([2, 2], vec![-1.0, 1.0, -2.0, 2.0])?;
+        let actual = a.try_sub(&mean.view())?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_prod_min_max() -> ArrResult<()> {
+        arrs!(let a = Array([2, 3], vec![3, 1, 2, 5, -4, 6]));
+
+        assert_eq!(ArrayBase::new([3], vec![3, 10, -24])?, a.prod(0)?);
+        assert_eq!(ArrayBase::new([2], vec![-4, 1])?, a.min(1)?);
+        Ok(assert_eq!(ArrayBase::new([2], vec![3, 6])?, a.max(1)?))
+    }
+
+    #[test]
+    fn test_all() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![1, 2, 3, 4]));
+
+        assert_eq!(10, a.sum_all()?);
+        assert_eq!(24, a.prod_all()?);
+        assert_eq!(2, a.mean_all()?);
+        assert_eq!(1, a.min_all()?);
+        Ok(assert_eq!(4, a.max_all()?))
+    }
+
+    #[test]
+    fn test_max_nan() -> ArrResult<()> {
+        arrs!(let a = [1.0, f64::NAN, 3.0]);
+
+        Ok(assert!(a.max_all()?.is_nan()))
+    }
+
+    #[test]
+    fn test_reduce_empty() -> ArrResult<()> {
+        let a = ArrayBase::<f32, 2>::new([0, 2], vec![])?;
+
+        assert_eq!(
+            ArrayBase::new([1, 2], vec![0.0, 0.0])?,
+            a.view().sum_keepdims(0)?
+        );
+        Ok(assert_eq!(Error::ReduceEmpty, a.view().min(0).unwrap_err()))
+    }
+
+    #[test]
+    fn test_reduce_err_axis() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![1, 2, 3, 4]));
+
+        let expected = Error::AxisOutOfBounds { axis: 2, ndims: 2 };
+        let actual = a.sum(2).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod sort_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_argmax_axis() -> ArrResult<()> {
+        // two samples of three class scores each
+        arrs!(let scores = Array([3, 2], vec![0.1, 0.7, 0.2, 0.5, 0.3, 0.5]));
+
+        assert_eq!(ArrayBase::new([2], vec![1, 0])?, scores.argmax(0)?);
+        Ok(assert_eq!(
+            ArrayBase::new([3], vec![1, 0, 1])?,
+            scores.argmax(1)?
+        ))
+    }
+
+    #[test]
+    fn test_argmin_axis() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![4, 2, 2, 9, 1, 9]));
+
+        Ok(assert_eq!(ArrayBase::new([2], vec![1, 1])?, a.argmin(0)?))
+    }
+
+    #[test]
+    fn test_arg_all_and_nan() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![3.0, 1.0, 4.0, 1.5]));
+        arrs!(let b = [1.0, f32::NAN, 5.0, f32::NAN]);
+
+        assert_eq!([1, 0], a.argmin_all()?);
+        assert_eq!([0, 1], a.argmax_all()?);
+        Ok(assert_eq!([1], b.argmin_all()?))
+    }
+
+    #[test]
+    fn test_argsort_stable() -> ArrResult<()> {
+        arrs!(let a = Array([4, 2], vec![3, 1, 3, 0, 2, 2, 1, 2]));
+
+        let expected = ArrayBase::new([4, 2], vec![3, 1, 0, 2, 2, 0, 1, 3])?;
+        let actual = a.argsort(0)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_sort_axis() -> ArrResult<()> {
+        arrs!(let a = Array([2, 3], vec![5, 0, 1, 4, 3, 2]));
+
+        assert_eq!(ArrayBase::new([2, 3], vec![0, 5, 1, 4, 2, 3])?, a.sort());
+        Ok(assert_eq!(
+            ArrayBase::new([2, 3], vec![1, 0, 3, 2, 5, 4])?,
+            a.sort_axis(1)?
+        ))
+    }
+
+    #[test]
+    fn test_sort_nan_last() -> ArrResult<()> {
+        arrs!(let a = [2.0, f64::NAN, -1.0, 0.5]);
+        let sorted = a.sort();
+
+        assert_eq!([-1.0, 0.5, 2.0], sorted.view().as_slice().unwrap()[..3]);
+        assert!(sorted[[3]].is_nan());
+        Ok(assert_eq!(
+            ArrayBase::new([4], vec![2, 3, 0, 1])?,
+            a.argsort(0)?
+        ))
+    }
+
+    #[test]
+    fn test_argmax_err_empty() -> ArrResult<()> {
+        let a = ArrayBase::<i32, 2>::new([0, 3], vec![])?;
+
+        Ok(assert_eq!(
+            Error::ReduceEmpty,
+            a.view().argmax(0).unwrap_err()
+        ))
+    }
+}
+
+mod stats_tests {
+    use crate::array::{ArrResult, ArrayBase, Error, Interpolation};
+
+    #[test]
+    fn test_var_std_axis() -> ArrResult<()> {
+        arrs!(let a = Array([4, 2], vec![1, 2, 3, 4, 2, 4, 6, 8]));
+
+        assert_eq!(ArrayBase::new([2], vec![1.25, 5.0])?, a.var(0, 0)?);
+        assert_eq!(
+            ArrayBase::new([1, 2], vec![5.0 / 3.0, 20.0 / 3.0])?,
+            a.var_keepdims(0, 1)?
+        );
+        Ok(assert_eq!(
+            ArrayBase::new([2], vec![1.25f64.sqrt(), 5.0f64.sqrt()])?,
+            a.std(0, 0)?
+        ))
+    }
+
+    #[test]
+    fn test_var_stable() -> ArrResult<()> {
+        // a large offset wrecks the naive sum of squares formula
+        let values = (0..1000).map(|i| 1e9 + (i % 4) as f64).collect();
+        let a = ArrayBase::new([1000], values)?;
+
+        Ok(assert!((a.view().var_all(0)? - 1.25).abs() < 1e-6))
+    }
+
+    #[test]
+    fn test_var_err_ddof() -> ArrResult<()> {
+        arrs!(let a = [1.0f32]);
+
+        let expected = Error::VarDdof { len: 1, ddof: 1 };
+        let actual = a.std_all(1).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_median() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![7, 1, 4, 2, 2, 9]));
+
+        assert_eq!(ArrayBase::new([2], vec![4.0, 2.0])?, a.median(0)?);
+        Ok(assert_eq!(3.0, a.median_all()?))
+    }
+
+    #[test]
+    fn test_percentile_interpolation() -> ArrResult<()> {
+        arrs!(let a = [1, 2, 3, 4]);
+
+        assert_eq!(2.5, a.percentile_all(50.0, Interpolation::Linear)?);
+        assert_eq!(1.3, a.percentile_all(10.0, Interpolation::Linear)?);
+        assert_eq!(2.0, a.percentile_all(50.0, Interpolation::Lower)?);
+        assert_eq!(3.0, a.percentile_all(50.0, Interpolation::Higher)?);
+        assert_eq!(2.0, a.percentile_all(30.0, Interpolation::Nearest)?);
+        assert_eq!(3.0, a.percentile_all(50.0, Interpolation::Nearest)?);
+        Ok(assert_eq!(
+            1.5,
+            a.percentile_all(10.0, Interpolation::Midpoint)?
+        ))
+    }
+
+    #[test]
+    fn test_percentile_err_q() -> ArrResult<()> {
+        arrs!(let a = Array([2, 2], vec![1, 2, 3, 4]));
+
+        let expected = Error::Percentile { q: 101.0 };
+        let actual = a.percentile(101.0, 0, Interpolation::Linear).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_histogram() -> ArrResult<()> {
+        arrs!(let a = [0.0, 1.0, 1.5, 2.0, 4.0, f64::NAN]);
+
+        let (counts, edges) = a.histogram(4, None)?;
+
+        assert_eq!(ArrayBase::new([4], vec![1, 2, 1, 1])?, counts);
+        Ok(assert_eq!(
+            ArrayBase::new([5], vec![0.0, 1.0, 2.0, 3.0, 4.0])?,
+            edges
+        ))
+    }
+
+    #[test]
+    fn test_histogram_range() -> ArrResult<()> {
+        arrs!(let a = [0, 5, 5, 9, 10]);
+
+        let (counts, edges) = a.histogram(2, Some((5.0, 9.0)))?;
+
+        assert_eq!(ArrayBase::new([2], vec![2, 1])?, counts);
+        assert_eq!(ArrayBase::new([3], vec![5.0, 7.0, 9.0])?, edges);
+
+        let expected = Error::Histogram {
+            bins: 0,
+            range: (5.0, 9.0),
+        };
+        Ok(assert_eq!(
+            expected,
+            a.histogram(0, Some((5.0, 9.0))).unwrap_err()
+        ))
+    }
+}
+
+mod scan_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_cumsum() -> ArrResult<()> {
+        arrs!(let a = Array([3, 2], vec![1, 2, 3, 4, 5, 6]));
+
+        assert_eq!(
+            ArrayBase::new([3, 2], vec![1, 3, 6, 4, 9, 15])?,
+            a.cumsum(0)?
+        );
+        Ok(assert_eq!(
+            ArrayBase::new([3, 2], vec![1, 2, 3, 5, 7, 9])?,
+            a.cumsum(1)?
+        ))
+    }
+
+    #[test]
+    fn test_cumprod_strided() -> ArrResult<()> {
+        arrs!(let a = Array([2, 3], vec![1, 2, 3, 4, 5, 6]));
+        let t = a.t();
+
+        let expected = ArrayBase::new([3, 2], vec![1, 3, 15, 2, 8, 48])?;
+        let actual = t.cumprod(0)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_diff() -> ArrResult<()> {
+        arrs!(let a = Array([4, 2], vec![1, 2, 4, 7, 0, 0, 5, 5]));
+
+        assert_eq!(
+            ArrayBase::new([3, 2], vec![1, 2, 3, 0, 5, 0])?,
+            a.diff(1, 0)?
+        );
+        assert_eq!(ArrayBase::new([2, 2], vec![1, 1, 5, -5])?, a.diff(2, 0)?);
+        assert_eq!(ArrayBase::new([4, 1], vec![-1, -2, 1, -2])?, a.diff(1, 1)?);
+        Ok(assert_eq!(ArrayBase::new([0, 2], vec![])?, a.diff(5, 0)?))
+    }
+
+    #[test]
+    fn test_gradient() -> ArrResult<()> {
+        arrs!(let a = Array([4, 2], vec![1, 2, 4, 7, 0, 0, 0, 0]));
+
+        let expected = ArrayBase::new([4, 2], vec![1.0, 1.5, 2.5, 3.0, 0.0, 0.0, 0.0, 0.0])?;
+        let actual = a.gradient(0)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_gradient_err_len() -> ArrResult<()> {
+        arrs!(let a = Array([3, 1], vec![1.0, 2.0, 3.0]));
+
+        let expected = Error::Gradient { axis: 1, len: 1 };
+        let actual = a.gradient(1).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod cov_tests {
+    use crate::array::{ArrResult, ArrayBase, Error};
+
+    #[test]
+    fn test_cov_rowvar() -> ArrResult<()> {
+        arrs!(let a = Array([4, 2], vec![1, 2, 3, 4, 2, 4, 6, 8]));
+
+        let expected = ArrayBase::new([2, 2], vec![5.0 / 3.0, 10.0 / 3.0, 10.0 / 3.0, 20.0 / 3.0])?;
+        let actual = a.cov(true)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_cov_cols() -> ArrResult<()> {
+        // three observations of two variables, one per row
+        arrs!(let a = Array([2, 3], vec![0, 2, 1, 1, 2, 0]));
+
+        let expected = ArrayBase::new([2, 2], vec![1.0, -1.0, -1.0, 1.0])?;
+        let actual = a.cov(false)?;
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_corrcoef() -> ArrResult<()> {
+        arrs!(let a = Array([4, 3], vec![1, 2, 3, 4, 2, 4, 6, 8, 4, 3, 2, 1]));
+        let corr = a.corrcoef(true)?;
+
+        let expected = [1.0, 1.0, -1.0, 1.0, 1.0, -1.0, -1.0, -1.0, 1.0];
+        for (&e, &c) in expected.iter().zip(corr.view().iter()) {
+            assert!((e - c).abs() < 1e-12);
+        }
+        Ok(assert_eq!(&[3, 3], corr.view().dims()))
+    }
+
+    #[test]
+    fn test_cov_err_len() -> ArrResult<()> {
+        arrs!(let a = Array([1, 2], vec![1.0, 2.0]));
+
+        let expected = Error::VarDdof { len: 1, ddof: 1 };
+        let actual = a.cov(true).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+}
+
+mod summation_tests {
+    use crate::array::{gemm, ArrResult, ArrayBase, Error, Summation};
+
+    /// Values with no exact `f32` representation, and their exact `f64` sum
+    fn values(len: usize) -> (Vec<f32>, f64) {
+        let values: Vec<f32> = (0..len).map(|i| 0.1 + (i % 7) as f32 * 0.01).collect();
+        let reference = values.iter().map(|&x| f64::from(x)).sum();
+        (values, reference)
+    }
+
+    fn rel_err(actual: f32, reference: f64) -> f64 {
+        ((f64::from(actual) - reference) / reference).abs()
+    }
+
+    #[test]
+    fn test_strategies_against_f64() {
+        let (values, reference) = values(1 << 20);
+
+        let naive = Summation::Naive.sum(values.iter().copied());
+        let pairwise = Summation::Pairwise.sum(values.iter().copied());
+        let kahan = Summation::Kahan.sum(values.iter().copied());
+
+        assert!(rel_err(naive, reference) > 1e-4);
+        assert!(rel_err(pairwise, reference) < 1e-6);
+        assert!(rel_err(kahan, reference) < 1e-7);
+    }
+
+    #[test]
+    fn test_integers_exact() {
+        let values = (1..=100).collect::<Vec<i32>>();
+
+        for &summation in [Summation::Naive, Summation::Pairwise, Summation::Kahan].iter() {
+            assert_eq!(5050, summation.sum(values.iter().copied()));
+        }
+    }
+
+    #[test]
+    fn test_pairwise_odd_lengths() {
+        for len in [0usize, 1, 7, 8, 9, 17, 100].iter().copied() {
+            let values = (0..len).map(|i| i as f64).collect::<Vec<_>>();
+            let expected = (len * len.saturating_sub(1) / 2) as f64;
+
+            assert_eq!(expected, Summation::Pairwise.sum(values));
+        }
+    }
+
+    #[test]
+    fn test_sum_default_pairwise() -> ArrResult<()> {
+        let (values, reference) = values(1 << 20);
+        let a = ArrayBase::new([1 << 20], values)?;
+
+        assert!(rel_err(a.view().sum_all()?, reference) < 1e-6);
+        Ok(assert!(
+            rel_err(a.view().sum_all_with(Summation::Naive)?, reference) > 1e-4
+        ))
+    }
+
+    #[test]
+    fn test_dot_784() -> ArrResult<()> {
+        // one MNIST image against one row of weights
+        let (pixels, _) = values(784);
+        let weights: Vec<f32> = (0..784).map(|i| 1.0 - (i % 5) as f32 * 0.3).collect();
+        let reference: f64 = pixels
+            .iter()
+            .zip(weights.iter())
+            .map(|(&p, &w)| f64::from(p) * f64::from(w))
+            .sum();
+        let (pixels, weights) = (
+            ArrayBase::new([784], pixels)?,
+            ArrayBase::new([784], weights)?,
+        );
+
+        let kahan = pixels.view().dot_with(&weights.view(), Summation::Kahan)?;
+
+        Ok(assert!(rel_err(kahan, reference) < 1e-7))
+    }
+
+    #[test]
+    fn test_dot_err() -> ArrResult<()> {
+        arrs!(let a = [1, 2, 3]);
+        arrs!(let b = [1, 2]);
+
+        let expected = Error::MatMul {
+            cols_a: 3,
+            rows_b: 2,
+        };
+        let actual = a.dot(&b).unwrap_err();
+
+        Ok(assert_eq!(expected, actual))
+    }
+
+    #[test]
+    fn test_matmul_with_summation() -> ArrResult<()> {
+        let (m, k, n) = (3, 4096, 5);
+        let (a, _) = values(m * k);
+        let b: Vec<f32> = (0..k * n).map(|i| 1.0 + (i % 3) as f32 * 0.1).collect();
+
+        let mut reference = vec![0.0; m * n];
+        for i in 0..m {
+            for j in 0..n {
+                reference[i * n + j] = (0..k)
+                    .map(|p| f64::from(a[i * k + p]) * f64::from(b[p * n + j]))
+                    .sum();
+            }
+        }
+
+        let mut summed = vec![0.0; m * n];
+        gemm::summed(m, k, n, &a, &b, &mut summed, 2, Summation::Kahan);
+        for (&c, &r) in summed.iter().zip(reference.iter()) {
+            assert!(rel_err(c, r) < 1e-7);
+        }
+
+        let (a, b) = (ArrayBase::new([k, m], a)?, ArrayBase::new([n, k], b)?);
+        let actual = a
+            .view()
+            .matmul_with_summation(&b.view(), Summation::Kahan)?;
+
+        Ok(assert_eq!(ArrayBase::new([n, m], summed)?, actual))
+    }
+
+    #[test]
+    fn test_matmul_matches_dot() -> ArrResult<()> {
+        let (pixels, _) = values(784);
+        let weights: Vec<f32> = (0..784).map(|i| 1.0 - (i % 5) as f32 * 0.3).collect();
+        let (pixels, weights) = (
+            ArrayBase::new([784], pixels)?,
+            ArrayBase::new([784], weights)?,
+        );
+
+        let expected = pixels.view().dot(&weights.view())?;
+        let actual = pixels.view().matmul(&weights.view())?;
+
+        Ok(assert_eq!(expected, actual[[0]]))
+    }
+
+    #[test]
+    fn test_summed_zero_inner_dim() {
+        let mut c = vec![1.0f32; 6];
+        gemm::summed(2, 0, 3, &[], &[], &mut c, 2, Summation::Pairwise);
+
+        assert_eq!(vec![0.0; 6], c);
+    }
+}
+
 /*
 mod array_idx_tests {
     use crate::array::{ArrResult, Shape};